@@ -16,7 +16,7 @@ unsafe_impl_value_type!(in quest_hook::libil2cpp for Vector3 => UnityEngine.Vect
 
 #[hook("UnityEngine", "RigidBody", "set_position")]
 fn set_position(this: &mut Il2CppObject, new_position: Vector3) {
-    let old_position: Vector3 = this.invoke("get_position", ()).unwrap();
+    let old_position: Vector3 = this.get("position").unwrap();
     debug!("{:?} -> {:?}", old_position, new_position);
 
     set_position.original(this, new_position)
@@ -1,21 +1,65 @@
 use std::ffi::c_void;
+use std::fs;
+use std::os::raw::{c_int, c_long};
 use std::ptr::null_mut;
-use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::atomic::{AtomicI32, AtomicPtr, AtomicU32, Ordering};
 
 extern "C" {
-    fn A64HookFunction(symbol: *const c_void, replace: *const c_void, result: *mut *mut c_void);
+    fn A64HookFunctionV(
+        symbol: *const c_void,
+        replace: *const c_void,
+        rwx: *mut c_void,
+        rwx_size: usize,
+    ) -> *mut c_void;
+
+    fn getpagesize() -> c_int;
+    fn mmap(
+        addr: *mut c_void,
+        len: usize,
+        prot: c_int,
+        flags: c_int,
+        fd: c_int,
+        offset: c_long,
+    ) -> *mut c_void;
+    fn munmap(addr: *mut c_void, len: usize) -> c_int;
+    fn mprotect(addr: *mut c_void, len: usize, prot: c_int) -> c_int;
+    fn __clear_cache(begin: *mut c_void, end: *mut c_void);
 }
 
+const PROT_READ: c_int = 0x1;
+const PROT_WRITE: c_int = 0x2;
+const PROT_EXEC: c_int = 0x4;
+
+const MAP_PRIVATE: c_int = 0x02;
+const MAP_ANONYMOUS: c_int = 0x20;
+const MAP_FAILED: *mut c_void = !0 as *mut c_void;
+
+/// Maximum number of instructions overwritten by `A64HookFunctionV`
+const PROLOGUE_LEN: usize = 5;
+
 /// A function hook specific to `ARMv8` Android
+///
+/// The trampoline to the original function is allocated in a page of its own,
+/// which is freed when the hook is uninstalled.
 #[derive(Debug)]
 pub struct Hook {
+    target: AtomicPtr<u32>,
+    prologue: [AtomicU32; PROLOGUE_LEN],
+    patch: [AtomicU32; PROLOGUE_LEN],
+    protection: AtomicI32,
     original: AtomicPtr<c_void>,
 }
 
 impl Hook {
     /// Creates a new, unitialized hook
     pub const fn new() -> Self {
+        #[allow(clippy::declare_interior_mutable_const)]
+        const ZERO: AtomicU32 = AtomicU32::new(0);
         Self {
+            target: AtomicPtr::new(null_mut()),
+            prologue: [ZERO; PROLOGUE_LEN],
+            patch: [ZERO; PROLOGUE_LEN],
+            protection: AtomicI32::new(0),
             original: AtomicPtr::new(null_mut()),
         }
     }
@@ -23,17 +67,99 @@ impl Hook {
     /// Installes the hook by redirecting `target` to `hook`, returning true on
     /// success
     ///
+    /// Installing a hook which is already installed fails.
+    ///
     /// # Safety
     /// `target` and `hook` must have the same signature and calling convention
     pub unsafe fn install(&self, target: *const (), hook: *const ()) -> bool {
-        let mut original: *mut c_void = null_mut();
+        let target = target as *mut u32;
+        if self
+            .target
+            .compare_exchange(null_mut(), target, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return false;
+        }
+
+        // Save the prologue and protection before they get overwritten so they
+        // can be restored
+        let protection = page_protection(target as usize).unwrap_or(PROT_READ | PROT_EXEC);
+        for (i, saved) in self.prologue.iter().enumerate() {
+            saved.store(target.add(i).read_volatile(), Ordering::SeqCst);
+        }
 
-        A64HookFunction(target.cast(), hook.cast(), &mut original);
+        let page_size = getpagesize() as usize;
+        let rwx = PROT_READ | PROT_WRITE | PROT_EXEC;
+        let trampoline = mmap(
+            null_mut(),
+            page_size,
+            rwx,
+            MAP_PRIVATE | MAP_ANONYMOUS,
+            -1,
+            0,
+        );
+        if trampoline == MAP_FAILED {
+            self.target.store(null_mut(), Ordering::SeqCst);
+            return false;
+        }
 
+        let original = A64HookFunctionV(target.cast(), hook.cast(), trampoline, page_size);
+        if original.is_null() {
+            munmap(trampoline, page_size);
+            self.target.store(null_mut(), Ordering::SeqCst);
+            return false;
+        }
+
+        // `A64HookFunctionV` leaves the target writable
+        protect(target, protection);
+        for (i, patched) in self.patch.iter().enumerate() {
+            patched.store(target.add(i).read_volatile(), Ordering::SeqCst);
+        }
+
+        self.protection.store(protection, Ordering::SeqCst);
         self.original.store(original, Ordering::SeqCst);
         true
     }
 
+    /// Uninstalls the hook by restoring the original prologue and protection
+    /// of the target and freeing the trampoline, returning true on success
+    ///
+    /// Uninstalling fails if the target was patched again since the hook was
+    /// installed, for example by another hook installed on top of this one,
+    /// since restoring the original prologue would remove the other hook.
+    ///
+    /// # Safety
+    /// No thread may be executing the trampoline, and any pointer previously
+    /// returned by [`Hook::original`] must not be used afterwards
+    pub unsafe fn uninstall(&self) -> bool {
+        let target = self.target.load(Ordering::SeqCst);
+        let original = self.original.load(Ordering::SeqCst);
+        if target.is_null() || original.is_null() {
+            return false;
+        }
+        for (i, patched) in self.patch.iter().enumerate() {
+            if target.add(i).read_volatile() != patched.load(Ordering::SeqCst) {
+                return false;
+            }
+        }
+
+        if !protect(target, PROT_READ | PROT_WRITE | PROT_EXEC) {
+            return false;
+        }
+        for (i, saved) in self.prologue.iter().enumerate() {
+            target.add(i).write_volatile(saved.load(Ordering::SeqCst));
+        }
+        __clear_cache(target.cast(), target.add(PROLOGUE_LEN).cast());
+        protect(target, self.protection.load(Ordering::SeqCst));
+
+        // The trampoline is the page allocated when installing
+        munmap(original, getpagesize() as usize);
+
+        self.original.store(null_mut(), Ordering::SeqCst);
+        self.target.store(null_mut(), Ordering::SeqCst);
+        true
+    }
+
     /// Whether the hook is installed
     pub fn is_installed(&self) -> bool {
         !self.original.load(Ordering::SeqCst).is_null()
@@ -48,3 +174,38 @@ impl Hook {
         }
     }
 }
+
+/// Changes the protection of the pages containing the prologue of `target`
+unsafe fn protect(target: *mut u32, protection: c_int) -> bool {
+    let start = target as usize;
+    let end = start + PROLOGUE_LEN * 4;
+    let page_start = start & !(getpagesize() as usize - 1);
+    mprotect(page_start as *mut c_void, end - page_start, protection) == 0
+}
+
+/// Protection of the page containing `addr`, read from `/proc/self/maps`
+fn page_protection(addr: usize) -> Option<c_int> {
+    let maps = fs::read_to_string("/proc/self/maps").ok()?;
+    maps.lines().find_map(|line| {
+        let mut fields = line.split_ascii_whitespace();
+        let (start, end) = fields.next()?.split_once('-')?;
+        let start = usize::from_str_radix(start, 16).ok()?;
+        let end = usize::from_str_radix(end, 16).ok()?;
+        if !(start..end).contains(&addr) {
+            return None;
+        }
+
+        let perms = fields.next()?.as_bytes();
+        let mut protection = 0;
+        for (&perm, (flag, prot)) in
+            perms
+                .iter()
+                .zip([(b'r', PROT_READ), (b'w', PROT_WRITE), (b'x', PROT_EXEC)])
+        {
+            if perm == flag {
+                protection |= prot;
+            }
+        }
+        Some(protection)
+    })
+}
@@ -1,22 +1,32 @@
 use std::os::raw::c_int;
 use std::ptr::null_mut;
-use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::atomic::{AtomicPtr, AtomicU32, Ordering};
 
 extern "C" {
     fn registerInlineHook(target_addr: u32, new_addr: u32, proto_addr: *mut *mut u32) -> c_int;
     fn inlineHook(target_addr: u32) -> c_int;
+    fn inlineUnHook(target_addr: u32) -> c_int;
 }
 
+/// Maximum number of words overwritten by `inlineHook`, in Thumb mode
+const PATCH_LEN: usize = 3;
+
 /// A function hook specific to `ARMv7` Android
 #[derive(Debug)]
 pub struct Hook {
+    target: AtomicU32,
+    patch: [AtomicU32; PATCH_LEN],
     original: AtomicPtr<u32>,
 }
 
 impl Hook {
     /// Creates a new, unitialized hook
     pub const fn new() -> Self {
+        #[allow(clippy::declare_interior_mutable_const)]
+        const ZERO: AtomicU32 = AtomicU32::new(0);
         Self {
+            target: AtomicU32::new(0),
+            patch: [ZERO; PATCH_LEN],
             original: AtomicPtr::new(null_mut()),
         }
     }
@@ -24,6 +34,8 @@ impl Hook {
     /// Installes the hook by redirecting `target` to `hook`, returning true on
     /// success
     ///
+    /// Installing a hook which is already installed fails.
+    ///
     /// # Safety
     /// `target` and `hook` must have the same signature and calling convention
     pub unsafe fn install(&self, target: *const (), hook: *const ()) -> bool {
@@ -31,14 +43,53 @@ impl Hook {
         let hook = hook as u32;
         let mut original: *mut u32 = null_mut();
 
+        if self
+            .target
+            .compare_exchange(0, target, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return false;
+        }
+
         if registerInlineHook(target, hook, &mut original) != 0 || inlineHook(target) != 0 {
+            self.target.store(0, Ordering::SeqCst);
             return false;
         }
 
+        for (patched, word) in self.patch.iter().zip(read_patch(target)) {
+            patched.store(word, Ordering::SeqCst);
+        }
         self.original.store(original, Ordering::SeqCst);
         true
     }
 
+    /// Uninstalls the hook by restoring the original instructions and
+    /// protection of the target and freeing the trampoline, which
+    /// `inlineUnHook` all takes care of, returning true on success
+    ///
+    /// Uninstalling fails if the target was patched again since the hook was
+    /// installed, for example by another hook installed on top of this one,
+    /// since restoring the original instructions would remove the other hook.
+    ///
+    /// # Safety
+    /// No thread may be executing the trampoline, and any pointer previously
+    /// returned by [`Hook::original`] must not be used afterwards
+    pub unsafe fn uninstall(&self) -> bool {
+        if !self.is_installed() {
+            return false;
+        }
+
+        let target = self.target.load(Ordering::SeqCst);
+        let patch = self.patch.iter().map(|p| p.load(Ordering::SeqCst));
+        if !read_patch(target).into_iter().eq(patch) || inlineUnHook(target) != 0 {
+            return false;
+        }
+
+        self.original.store(null_mut(), Ordering::SeqCst);
+        self.target.store(0, Ordering::SeqCst);
+        true
+    }
+
     /// Whether the hook is installed
     pub fn is_installed(&self) -> bool {
         !self.original.load(Ordering::SeqCst).is_null()
@@ -53,3 +104,9 @@ impl Hook {
         }
     }
 }
+
+/// Reads the words at the start of `target` which are overwritten by the
+/// patch, ignoring the Thumb bit of its address
+unsafe fn read_patch(target: u32) -> [u32; PATCH_LEN] {
+    ((target & !1) as *const [u32; PATCH_LEN]).read_unaligned()
+}
@@ -1,30 +1,77 @@
-use std::sync::OnceLock;
+use std::ptr;
+use std::sync::RwLock;
 
 use detour::RawDetour;
 
+/// Number of bytes `detour` overwrites at the start of the target
+const PATCH_LEN: usize = 5;
+
 /// A function hook that works across most platforms
 #[derive(Debug)]
 pub struct Hook {
-    detour: OnceLock<RawDetour>,
+    detour: RwLock<Option<Installed>>,
 }
 
+#[derive(Debug)]
+struct Installed {
+    detour: RawDetour,
+    target: *const (),
+    patch: [u8; PATCH_LEN],
+}
+
+// The target is only used to check whether it is still patched
+unsafe impl Send for Installed {}
+unsafe impl Sync for Installed {}
+
 impl Hook {
     /// Creates a new, unitialized hook
     pub const fn new() -> Self {
         Self {
-            detour: OnceLock::new(),
+            detour: RwLock::new(None),
         }
     }
 
     /// Installes the hook by redirecting `target` to `hook`, returning true on
     /// success
     ///
+    /// Installing a hook which is already installed fails.
+    ///
     /// # Safety
     /// `target` and `hook` must have the same signature and calling convention
     pub unsafe fn install(&self, target: *const (), hook: *const ()) -> bool {
+        let mut detour = self.detour.write().unwrap();
+        if detour.is_some() {
+            return false;
+        }
+
         match RawDetour::new(target, hook) {
-            Ok(detour) if detour.enable().is_ok() => {
-                self.detour.set(detour).ok();
+            Ok(d) if d.enable().is_ok() => {
+                *detour = Some(Installed {
+                    detour: d,
+                    target,
+                    patch: read_patch(target),
+                });
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Uninstalls the hook by restoring the original target and freeing the
+    /// trampoline, returning true on success
+    ///
+    /// Uninstalling fails if the target was patched again since the hook was
+    /// installed, for example by another hook installed on top of this one,
+    /// since restoring the original target would remove the other hook.
+    ///
+    /// # Safety
+    /// No thread may be executing the trampoline, and any pointer previously
+    /// returned by [`Hook::original`] must not be used afterwards
+    pub unsafe fn uninstall(&self) -> bool {
+        let mut detour = self.detour.write().unwrap();
+        match detour.as_ref() {
+            Some(i) if read_patch(i.target) == i.patch && i.detour.disable().is_ok() => {
+                *detour = None;
                 true
             }
             _ => false,
@@ -33,12 +80,21 @@ impl Hook {
 
     /// Whether the hook is installed
     pub fn is_installed(&self) -> bool {
-        self.detour.get().is_some()
+        self.detour.read().unwrap().is_some()
     }
 
     /// Returns the address of a trampoline function to the original target, if
     /// installed
     pub fn original(&self) -> Option<*const ()> {
-        self.detour.get().map(|d| d.trampoline() as *const ())
+        self.detour
+            .read()
+            .unwrap()
+            .as_ref()
+            .map(|i| i.detour.trampoline() as *const ())
     }
 }
+
+/// Reads the bytes at the start of `target` which are overwritten by the patch
+unsafe fn read_patch(target: *const ()) -> [u8; PATCH_LEN] {
+    ptr::read_volatile(target.cast())
+}
@@ -1,4 +1,3 @@
-#![cfg_attr(not(target_os = "android"), feature(once_cell))]
 #![doc(html_root_url = "https://stackdoubleflow.github.io/quest-hook-rs/inline_hook")]
 #![warn(
     clippy::all,
@@ -120,4 +119,31 @@ mod tests {
             unsafe { transmute::<*const (), fn(usize, usize) -> usize>(HOOK.original().unwrap()) };
         assert_eq!(original(2, 3), 5);
     }
+
+    #[test]
+    fn uninstall() {
+        static HOOK: Hook = Hook::new();
+
+        #[inline(never)]
+        fn sub(n1: usize, n2: usize) -> usize {
+            n1 - n2
+        }
+
+        #[inline(never)]
+        fn div(n1: usize, n2: usize) -> usize {
+            n1 / n2
+        }
+
+        assert!(!unsafe { HOOK.uninstall() });
+
+        assert!(unsafe { HOOK.install(sub as _, div as _) });
+        assert_eq!(sub(6, 3), 2);
+
+        assert!(unsafe { HOOK.uninstall() } && !HOOK.is_installed());
+        assert!(HOOK.original().is_none());
+        assert_eq!(sub(6, 3), 3);
+
+        assert!(unsafe { HOOK.install(sub as _, div as _) });
+        assert_eq!(sub(6, 3), 2);
+    }
 }
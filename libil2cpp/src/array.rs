@@ -1,33 +1,44 @@
 use std::marker::PhantomData;
-use std::mem::transmute;
 use std::ops::{Deref, DerefMut};
 use std::{fmt, ptr, slice};
 
-use crate::{raw, Il2CppClass, Il2CppObject, Type, WrapRaw};
+use crate::{raw, GcHandle, Il2CppClass, Il2CppError, Il2CppObject, Type, WrapRaw};
 
 /// An il2cpp array
 #[repr(transparent)]
 pub struct Il2CppArray<T: Type>(raw::Il2CppArray, PhantomData<[T]>);
 
 impl<T: Type> Il2CppArray<T> {
-    /// Creates an array from an iterator
-    pub fn new<'a, I>(items: I) -> &'a mut Self
+    /// Creates an array from an iterator, rooted by a [`GcHandle`]
+    pub fn new<'a, I>(items: I) -> GcHandle<Self>
+    where
+        I: IntoIterator<Item = T::Held<'a>>,
+        I::IntoIter: ExactSizeIterator<Item = T::Held<'a>>,
+    {
+        unsafe { GcHandle::new(Self::new_unrooted(items)) }
+    }
+
+    /// Creates an array from an iterator, without rooting it
+    ///
+    /// # Safety
+    /// Nothing prevents the array from being collected, so the returned
+    /// reference must not be used after the next garbage collection unless the
+    /// array is rooted by other means
+    pub unsafe fn new_unrooted<'a, I>(items: I) -> &'a mut Self
     where
         I: IntoIterator<Item = T::Held<'a>>,
         I::IntoIter: ExactSizeIterator<Item = T::Held<'a>>,
     {
         let items = items.into_iter();
         let len = items.len();
-        let arr = unsafe { raw::array_new(T::class().raw(), len) }.unwrap();
+        let arr = raw::array_new(T::class().raw(), len).unwrap();
         let data_ptr =
             ((arr as *mut _ as isize) + (raw::kIl2CppSizeOfArray as isize)) as *mut T::Held<'a>;
         for (i, elem) in items.into_iter().enumerate() {
-            unsafe {
-                let ptr = data_ptr.add(i);
-                ptr::write_unaligned(ptr, elem);
-            }
+            let ptr = data_ptr.add(i);
+            ptr::write_unaligned(ptr, elem);
         }
-        unsafe { Self::wrap_mut(arr) }
+        Self::wrap_mut(arr)
     }
 
     /// Slice of values in the array
@@ -48,12 +59,7 @@ impl<T: Type> Il2CppArray<T> {
 
     /// Length of the array
     pub fn len(&self) -> usize {
-        let raw = self.raw();
-        let bounds: Option<&raw::Il2CppArrayBounds> = unsafe { transmute(raw.bounds) };
-        match bounds {
-            Some(bounds) => bounds.length,
-            None => raw.max_length,
-        }
+        self.raw().max_length
     }
 
     /// Whether the array is empty
@@ -73,9 +79,9 @@ unsafe impl<T: Type> Type for Il2CppArray<T> {
     const NAMESPACE: &'static str = "System";
     const CLASS_NAME: &'static str = "Array";
 
-    fn class() -> &'static Il2CppClass {
-        let class = unsafe { raw::array_class_get(T::class().raw(), 1) };
-        unsafe { Il2CppClass::wrap(class) }
+    fn try_class() -> Result<&'static Il2CppClass, Il2CppError<'static>> {
+        let class = unsafe { raw::array_class_get(T::try_class()?.raw(), 1) };
+        Ok(unsafe { Il2CppClass::wrap(class) })
     }
 
     fn matches_reference_argument(ty: &crate::Il2CppType) -> bool {
@@ -122,7 +128,7 @@ impl<T: Type> DerefMut for Il2CppArray<T> {
 
 #[cfg(feature = "serde")]
 mod serde {
-    use super::{fmt, ptr, raw, Il2CppArray, PhantomData, Type, WrapRaw};
+    use super::{fmt, ptr, raw, GcHandle, Il2CppArray, PhantomData, Type, WrapRaw};
 
     use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
     use serde::ser::{Serialize, Serializer};
@@ -133,12 +139,13 @@ mod serde {
     where
         T::Held<'de>: Deserialize<'de>,
     {
-        fn visit_self<A>(mut seq: A, len: usize) -> Result<&'de mut Il2CppArray<T>, A::Error>
+        fn visit_self<A>(mut seq: A, len: usize) -> Result<GcHandle<Il2CppArray<T>>, A::Error>
         where
             A: SeqAccess<'de>,
         {
             let arr = unsafe { raw::array_new(T::class().raw(), len) }.unwrap();
-            let data_ptr = ((arr as *mut _ as isize) + (raw::kIl2CppSizeOfArray as isize))
+            let mut arr = unsafe { GcHandle::new(Il2CppArray::<T>::wrap_mut(arr)) };
+            let data_ptr = ((&mut *arr as *mut _ as isize) + (raw::kIl2CppSizeOfArray as isize))
                 as *mut T::Held<'de>;
             for i in 0..len {
                 unsafe {
@@ -146,7 +153,7 @@ mod serde {
                     ptr::write_unaligned(ptr, seq.next_element()?.unwrap());
                 }
             }
-            Ok(unsafe { Il2CppArray::wrap_mut(arr) })
+            Ok(arr)
         }
 
         fn visit_vec<A>(mut seq: A) -> Result<Vec<T::Held<'de>>, A::Error>
@@ -165,7 +172,7 @@ mod serde {
     where
         T::Held<'de>: Deserialize<'de>,
     {
-        type Value = &'de mut Il2CppArray<T>;
+        type Value = GcHandle<Il2CppArray<T>>;
 
         fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
             formatter.write_str("an array of C# compatible values")
@@ -182,7 +189,7 @@ mod serde {
         }
     }
 
-    impl<'de, T: Type> Deserialize<'de> for &'de mut Il2CppArray<T>
+    impl<'de, T: Type> Deserialize<'de> for GcHandle<Il2CppArray<T>>
     where
         T::Held<'de>: Deserialize<'de>,
     {
@@ -0,0 +1,198 @@
+use std::ffi::c_void;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+
+use crate::{Argument, Il2CppError, Il2CppType, Parameter, Type};
+
+/// A C# `ref` parameter of type `T`, such as `ref int` for `Ref<i32>`
+///
+/// This works for both value and reference types, and can be used as an
+/// argument to call methods as well as a parameter in hooks. Changes made by
+/// the called method are visible through the original reference once it
+/// returns. Hook parameters need to spell out the lifetime, as in
+/// `Ref<'_, i32>`.
+///
+/// Since the called method can store any value of the parameter type, `T` has
+/// to be exactly that type rather than a base or derived class of it.
+#[repr(transparent)]
+pub struct Ref<'a, T: Type> {
+    value: &'a mut T::HeldRaw,
+    marker: PhantomData<&'a mut T::Held<'a>>,
+}
+
+/// A C# `out` parameter of type `T`, such as `out int` for `Out<i32>`
+///
+/// Unlike [`Ref`], the value an `out` parameter points to may be uninitialized
+/// when it is received in a hook, so it can only be written to.
+#[repr(transparent)]
+pub struct Out<'a, T: Type> {
+    value: &'a mut MaybeUninit<T::HeldRaw>,
+    marker: PhantomData<&'a mut T::Held<'a>>,
+}
+
+impl<'a, T: Type> Ref<'a, T> {
+    /// Creates a `ref` argument from a reference to a value
+    ///
+    /// The value has to live for as long as it is borrowed, since the called
+    /// method can replace it with a value which lives for that long only.
+    pub fn new(value: &'a mut T::Held<'a>) -> Self {
+        Self {
+            value: unsafe { &mut *(value as *mut T::Held<'a>).cast() },
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: Type> Out<'a, T> {
+    /// Creates an `out` argument from a reference to a value, which will be
+    /// overwritten by the called method
+    ///
+    /// The value has to live for as long as it is borrowed, since the called
+    /// method can replace it with a value which lives for that long only.
+    pub fn new(value: &'a mut T::Held<'a>) -> Self {
+        Self {
+            value: unsafe { &mut *(value as *mut T::Held<'a>).cast() },
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates an `out` argument from a reference to possibly uninitialized
+    /// memory, which is initialized by the called method
+    pub fn uninit(value: &'a mut MaybeUninit<T::Held<'a>>) -> Self {
+        Self {
+            value: unsafe { &mut *(value as *mut MaybeUninit<T::Held<'a>>).cast() },
+            marker: PhantomData,
+        }
+    }
+
+    /// Writes a value to the parameter, returning a reference to it
+    pub fn write(&mut self, value: T::Held<'a>) -> &mut T::Held<'a> {
+        let ptr = self.as_mut_ptr();
+        unsafe {
+            ptr.write(value);
+            &mut *ptr
+        }
+    }
+
+    /// Raw pointer to the value of the parameter
+    pub fn as_mut_ptr(&mut self) -> *mut T::Held<'a> {
+        self.value.as_mut_ptr().cast()
+    }
+}
+
+impl<'a, T: Type> Deref for Ref<'a, T> {
+    type Target = T::Held<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self.value as *const T::HeldRaw).cast() }
+    }
+}
+
+impl<'a, T: Type> DerefMut for Ref<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *(self.value as *mut T::HeldRaw).cast() }
+    }
+}
+
+unsafe impl<T: Type> Argument for Ref<'_, T> {
+    type Type = Ref<'static, T>;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        ty.is_ref() && !ty.is_out() && ty.class() == T::class()
+    }
+
+    fn type_name() -> String {
+        format!("{}&", T::class().ty())
+    }
+
+    fn with_invokable<O>(&mut self, f: impl FnOnce(*mut c_void) -> O) -> O {
+        f((self.value as *mut T::HeldRaw).cast())
+    }
+}
+
+unsafe impl<T: Type> Argument for Out<'_, T> {
+    type Type = Out<'static, T>;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        ty.is_out() && ty.class() == T::class()
+    }
+
+    fn type_name() -> String {
+        format!("{}&", T::class().ty())
+    }
+
+    fn with_invokable<O>(&mut self, f: impl FnOnce(*mut c_void) -> O) -> O {
+        f(self.value.as_mut_ptr().cast())
+    }
+}
+
+unsafe impl<T: Type> Parameter for Ref<'_, T> {
+    type Actual = Self;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        ty.is_ref() && !ty.is_out() && ty.class() == T::class()
+    }
+
+    fn type_name() -> String {
+        format!("{}&", T::class().ty())
+    }
+
+    fn from_actual(actual: Self::Actual) -> Self {
+        actual
+    }
+    fn into_actual(self) -> Self::Actual {
+        self
+    }
+}
+
+unsafe impl<T: Type> Parameter for Out<'_, T> {
+    type Actual = Self;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        ty.is_out() && ty.class() == T::class()
+    }
+
+    fn type_name() -> String {
+        format!("{}&", T::class().ty())
+    }
+
+    fn from_actual(actual: Self::Actual) -> Self {
+        actual
+    }
+    fn into_actual(self) -> Self::Actual {
+        self
+    }
+}
+
+impl<'a, T: Type> fmt::Debug for Ref<'a, T>
+where
+    T::Held<'a>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Ref").field(&**self).finish()
+    }
+}
+
+impl<T: Type> fmt::Debug for Out<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Out").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,325 @@
+//! Process-wide cache of il2cpp lookups
+//!
+//! Lookups of classes, methods, fields and generic instantiations are cached
+//! across all threads. The cache is split in shards guarded by [`RwLock`]s so
+//! that concurrent lookups rarely contend.
+
+use std::any::TypeId;
+use std::borrow::{Borrow, Cow};
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, RwLock};
+use std::{array, fmt};
+
+use crate::{FieldInfo, Il2CppClass, MethodInfo};
+
+const SHARDS: usize = 16;
+
+pub(crate) static CLASSES: LazyLock<Map<StaticClassKey, &'static Il2CppClass>> =
+    LazyLock::new(Map::default);
+pub(crate) static METHODS: LazyLock<Map<StaticMemberKey, &'static MethodInfo>> =
+    LazyLock::new(Map::default);
+pub(crate) static STATIC_METHODS: LazyLock<Map<StaticMemberKey, &'static MethodInfo>> =
+    LazyLock::new(Map::default);
+pub(crate) static FIELDS: LazyLock<Map<StaticMemberKey, &'static FieldInfo>> =
+    LazyLock::new(Map::default);
+pub(crate) static GENERICS: LazyLock<Map<GenericKey, &'static Il2CppClass>> =
+    LazyLock::new(Map::default);
+pub(crate) static GENERIC_METHODS: LazyLock<Map<GenericKey, &'static MethodInfo>> =
+    LazyLock::new(Map::default);
+
+/// Clears every entry from the cache, without resetting statistics
+pub fn clear() {
+    CLASSES.clear();
+    METHODS.clear();
+    STATIC_METHODS.clear();
+    FIELDS.clear();
+    GENERICS.clear();
+    GENERIC_METHODS.clear();
+}
+
+/// Returns statistics about the usage of the cache
+pub fn stats() -> Stats {
+    Stats {
+        classes: CLASSES.stats(),
+        methods: METHODS.stats(),
+        static_methods: STATIC_METHODS.stats(),
+        fields: FIELDS.stats(),
+        generics: GENERICS.stats(),
+        generic_methods: GENERIC_METHODS.stats(),
+    }
+}
+
+/// Statistics about the usage of the cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Stats {
+    /// Statistics for class lookups
+    pub classes: CacheStats,
+    /// Statistics for method lookups
+    pub methods: CacheStats,
+    /// Statistics for `static` method lookups
+    pub static_methods: CacheStats,
+    /// Statistics for field lookups
+    pub fields: CacheStats,
+    /// Statistics for generic class instantiations
+    pub generics: CacheStats,
+    /// Statistics for generic method instantiations
+    pub generic_methods: CacheStats,
+}
+
+impl Stats {
+    /// Statistics for all kinds of lookups combined
+    pub fn total(&self) -> CacheStats {
+        [
+            self.classes,
+            self.methods,
+            self.static_methods,
+            self.fields,
+            self.generics,
+            self.generic_methods,
+        ]
+        .into_iter()
+        .fold(CacheStats::default(), |a, b| CacheStats {
+            hits: a.hits + b.hits,
+            misses: a.misses + b.misses,
+            entries: a.entries + b.entries,
+        })
+    }
+}
+
+/// Statistics about the usage of a single kind of lookup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CacheStats {
+    /// Number of lookups answered by the cache
+    pub hits: u64,
+    /// Number of lookups which had to go through il2cpp
+    pub misses: u64,
+    /// Number of entries currently in the cache
+    pub entries: usize,
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {} entries",
+            self.hits, self.misses, self.entries
+        )
+    }
+}
+
+/// Sharded concurrent map keeping track of its hits and misses
+pub(crate) struct Map<K, V> {
+    shards: [RwLock<HashMap<K, V>>; SHARDS],
+    hasher: RandomState,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<K, V> Default for Map<K, V> {
+    fn default() -> Self {
+        Self {
+            shards: array::from_fn(|_| Default::default()),
+            hasher: RandomState::new(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+}
+
+impl<K, V> Map<K, V>
+where
+    K: Hash + Eq,
+    V: Copy,
+{
+    fn shard<Q>(&self, key: &Q) -> &RwLock<HashMap<K, V>>
+    where
+        Q: Hash + ?Sized,
+    {
+        let hash = self.hasher.hash_one(key) as usize;
+        &self.shards[hash % SHARDS]
+    }
+
+    pub(crate) fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let value = self.shard(key).read().unwrap().get(key).copied();
+        let counter = match value {
+            Some(_) => &self.hits,
+            None => &self.misses,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
+    }
+
+    pub(crate) fn insert(&self, key: K, value: V) {
+        self.shard(&key).write().unwrap().insert(key, value);
+    }
+
+    fn clear(&self) {
+        for shard in &self.shards {
+            shard.write().unwrap().clear();
+        }
+    }
+
+    fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.shards.iter().map(|s| s.read().unwrap().len()).sum(),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+pub(crate) struct ClassKey<'a> {
+    pub(crate) namespace: Cow<'a, str>,
+    pub(crate) name: Cow<'a, str>,
+}
+
+#[derive(PartialEq, Eq, Hash)]
+pub(crate) struct StaticClassKey(ClassKey<'static>);
+
+impl<'a> From<ClassKey<'a>> for StaticClassKey {
+    fn from(ClassKey { namespace, name }: ClassKey<'a>) -> Self {
+        let namespace = namespace.into_owned().into();
+        let name = name.into_owned().into();
+        Self(ClassKey { namespace, name })
+    }
+}
+
+impl<'a> Borrow<ClassKey<'a>> for StaticClassKey {
+    fn borrow(&self) -> &ClassKey<'a> {
+        &self.0
+    }
+}
+
+/// Key for members of a class, which is identified by its address since
+/// instantiations of a generic class share the same namespace and name
+#[derive(PartialEq, Eq, Hash)]
+pub(crate) struct MemberKey<'a> {
+    pub(crate) class: usize,
+    pub(crate) name: Cow<'a, str>,
+    pub(crate) ty: Option<TypeId>,
+}
+
+#[derive(PartialEq, Eq, Hash)]
+pub(crate) struct StaticMemberKey(MemberKey<'static>);
+
+impl<'a> From<MemberKey<'a>> for StaticMemberKey {
+    fn from(MemberKey { class, name, ty }: MemberKey<'a>) -> Self {
+        let name = name.into_owned().into();
+        Self(MemberKey { class, name, ty })
+    }
+}
+
+impl<'a> Borrow<MemberKey<'a>> for StaticMemberKey {
+    fn borrow(&self) -> &MemberKey<'a> {
+        &self.0
+    }
+}
+
+/// Key for instantiations of a generic class or method, which is identified by
+/// its address
+#[derive(PartialEq, Eq, Hash)]
+pub(crate) struct GenericKey {
+    pub(crate) template: usize,
+    pub(crate) generics: TypeId,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+    use std::borrow::Cow;
+
+    use super::{
+        CacheStats, ClassKey, GenericKey, Map, MemberKey, StaticClassKey, StaticMemberKey,
+    };
+
+    fn class_key<'a>(namespace: &'a str, name: &'a str) -> ClassKey<'a> {
+        ClassKey {
+            namespace: Cow::Borrowed(namespace),
+            name: Cow::Borrowed(name),
+        }
+    }
+
+    #[test]
+    fn borrowed_lookup() {
+        let map = Map::<StaticClassKey, u32>::default();
+        let (namespace, name) = (String::from("System"), String::from("Int32"));
+        map.insert(class_key(&namespace, &name).into(), 1);
+        drop((namespace, name));
+
+        assert_eq!(map.get(&class_key("System", "Int32")), Some(1));
+        assert_eq!(map.get(&class_key("System", "Int64")), None);
+        assert_eq!(map.get(&class_key("", "SystemInt32")), None);
+    }
+
+    #[test]
+    fn member_keys() {
+        let map = Map::<StaticMemberKey, u32>::default();
+        let key = |class, ty| MemberKey {
+            class,
+            name: Cow::Borrowed("Add"),
+            ty,
+        };
+        map.insert(key(1, None).into(), 1);
+        map.insert(key(1, Some(TypeId::of::<fn(i32)>())).into(), 2);
+        map.insert(key(2, Some(TypeId::of::<fn(i32)>())).into(), 3);
+
+        assert_eq!(map.get(&key(1, None)), Some(1));
+        assert_eq!(map.get(&key(1, Some(TypeId::of::<fn(i32)>()))), Some(2));
+        assert_eq!(map.get(&key(2, Some(TypeId::of::<fn(i32)>()))), Some(3));
+        assert_eq!(map.get(&key(1, Some(TypeId::of::<fn(i64)>()))), None);
+        assert_eq!(map.get(&key(2, None)), None);
+    }
+
+    #[test]
+    fn generic_keys() {
+        let map = Map::<GenericKey, u32>::default();
+        let key = |template, generics| GenericKey { template, generics };
+        map.insert(key(1, TypeId::of::<(i32,)>()), 1);
+        map.insert(key(1, TypeId::of::<(i32, i64)>()), 2);
+
+        assert_eq!(map.get(&key(1, TypeId::of::<(i32,)>())), Some(1));
+        assert_eq!(map.get(&key(1, TypeId::of::<(i32, i64)>())), Some(2));
+        assert_eq!(map.get(&key(2, TypeId::of::<(i32,)>())), None);
+    }
+
+    #[test]
+    fn stats_and_clear() {
+        let map = Map::<u32, u32>::default();
+        assert_eq!(map.stats(), CacheStats::default());
+
+        for i in 0..100 {
+            map.insert(i, i * 2);
+        }
+        map.insert(0, 1);
+        assert_eq!(map.get(&0), Some(1));
+        assert_eq!(map.get(&99), Some(198));
+        assert_eq!(map.get(&100), None);
+        assert_eq!(
+            map.stats(),
+            CacheStats {
+                hits: 2,
+                misses: 1,
+                entries: 100,
+            }
+        );
+
+        map.clear();
+        assert_eq!(map.get(&0), None);
+        assert_eq!(
+            map.stats(),
+            CacheStats {
+                hits: 2,
+                misses: 2,
+                entries: 0,
+            }
+        );
+    }
+}
@@ -3,15 +3,51 @@ use std::ffi::{CStr, CString};
 use std::mem::transmute;
 use std::{fmt, ptr, slice};
 
+#[cfg(feature = "cache")]
+use crate::cache;
+use crate::typecheck::generic::type_array_of;
 use crate::{
-    raw, Arguments, FieldInfo, Generics, Il2CppException, Il2CppType, MethodInfo, Parameters,
-    Return, Returned, ThisParameter, Type, WrapRaw,
+    raw, Argument, Arguments, EventInfo, FieldInfo, GcHandle, Generics, Il2CppError,
+    Il2CppException, Il2CppType, MethodInfo, Parameters, PropertyInfo, Return, Returned,
+    ThisParameter, Type, WrapRaw,
 };
 
-#[cfg(feature = "unity2019")]
+#[cfg(feature = "dynamic")]
+use crate::runtime::{unity_version, UnityVersion};
+
+#[cfg(any(
+    feature = "unity2019",
+    feature = "unity2020",
+    feature = "unity2021",
+    feature = "unity2022"
+))]
 type FieldInfoSlice<'a> = &'a [FieldInfo];
 #[cfg(feature = "unity2018")]
 type FieldInfoSlice<'a> = &'a [&'static FieldInfo];
+#[cfg(feature = "dynamic")]
+type FieldInfoSlice<'a> = Vec<&'a FieldInfo>;
+
+/// Reads a field of the raw class which is laid out differently depending on
+/// the Unity version, which is everything after `typeHierarchy`
+macro_rules! versioned {
+    ($class:expr, $($field:tt)+) => {{
+        #[cfg(not(feature = "dynamic"))]
+        {
+            $class.raw().$($field)+
+        }
+
+        #[cfg(feature = "dynamic")]
+        {
+            match unity_version() {
+                UnityVersion::Unity2018 => $class.raw_2018().$($field)+,
+                UnityVersion::Unity2019 => $class.raw().$($field)+,
+                UnityVersion::Unity2020 => $class.raw_2020().$($field)+,
+                UnityVersion::Unity2021 => $class.raw_2021().$($field)+,
+                UnityVersion::Unity2022 => $class.raw_2022().$($field)+,
+            }
+        }
+    }};
+}
 
 /// An il2cpp class
 #[repr(transparent)]
@@ -26,11 +62,11 @@ impl Il2CppClass {
     pub fn find(namespace: &str, name: &str) -> Option<&'static Self> {
         #[cfg(feature = "cache")]
         let key = {
-            let key = cache::ClassCacheKey {
+            let key = cache::ClassKey {
                 namespace: namespace.into(),
                 name: name.into(),
             };
-            if let Some(class) = cache::CLASS_CACHE.with(|c| c.borrow().get(&key).copied()) {
+            if let Some(class) = cache::CLASSES.get(&key) {
                 debug!("cache hit");
                 return Some(class);
             }
@@ -63,7 +99,7 @@ impl Il2CppClass {
                 let class = unsafe { Self::wrap(class) };
 
                 #[cfg(feature = "cache")]
-                cache::CLASS_CACHE.with(move |c| c.borrow_mut().insert(key.into(), class));
+                cache::CLASSES.insert(key.into(), class);
 
                 return Some(class);
             }
@@ -72,10 +108,27 @@ impl Il2CppClass {
         None
     }
 
+    /// Find a class by namespace and name, instantiating it if the name
+    /// includes generic arguments, such as ``List`1<UnityEngine.GameObject>``
+    ///
+    /// Generic arguments are fully qualified names, such as `System.Int32`,
+    /// which can themselves include generic arguments.
+    #[crate::instrument(level = "debug")]
+    pub fn find_instantiation(namespace: &str, name: &str) -> Option<&'static Self> {
+        let (name, generics) = split_generics(name);
+        let class = Self::find(namespace, name)?;
+        if generics.is_empty() {
+            return Some(class);
+        }
+
+        let generics = find_all_qualified(&generics)?;
+        class.make_generic_with(&generics)
+    }
+
     /// Finds a generic method by namespace, name and generic parameters
     pub fn find_generic<G>(namespace: &str, name: &str) -> Option<&'static Self>
     where
-        G: Generics,
+        G: Generics + 'static,
     {
         Self::find(namespace, &format!("{}`{}", name, G::COUNT))?
             .make_generic::<G>()
@@ -95,16 +148,12 @@ impl Il2CppClass {
     {
         #[cfg(feature = "cache")]
         let key = {
-            let class_key = cache::ClassCacheKey {
-                namespace: self.namespace(),
-                name: self.name(),
-            };
-            let key = cache::MethodCacheKey {
-                class: class_key,
+            let key = cache::MemberKey {
+                class: self as *const Self as usize,
                 name: name.into(),
-                ty: std::any::TypeId::of::<fn(Self, A::Type) -> R::Type>(),
+                ty: Some(std::any::TypeId::of::<fn(Self, A::Type) -> R::Type>()),
             };
-            if let Some(method) = cache::METHOD_CACHE.with(|c| c.borrow().get(&key).copied()) {
+            if let Some(method) = cache::METHODS.get(&key) {
                 debug!("cache hit");
                 return Ok(method);
             }
@@ -126,7 +175,7 @@ impl Il2CppClass {
             } {
                 (mi, None) => {
                     #[cfg(feature = "cache")]
-                    cache::METHOD_CACHE.with(move |c| c.borrow_mut().insert(key.into(), mi));
+                    cache::METHODS.insert(key.into(), mi);
 
                     return Ok(mi);
                 }
@@ -149,16 +198,12 @@ impl Il2CppClass {
     {
         #[cfg(feature = "cache")]
         let key = {
-            let class_key = cache::ClassCacheKey {
-                namespace: self.namespace(),
-                name: self.name(),
-            };
-            let key = cache::MethodCacheKey {
-                class: class_key,
+            let key = cache::MemberKey {
+                class: self as *const Self as usize,
                 name: name.into(),
-                ty: std::any::TypeId::of::<fn((), A::Type) -> R::Type>(),
+                ty: Some(std::any::TypeId::of::<fn((), A::Type) -> R::Type>()),
             };
-            if let Some(method) = cache::METHOD_CACHE.with(|c| c.borrow().get(&key).copied()) {
+            if let Some(method) = cache::STATIC_METHODS.get(&key) {
                 debug!("cache hit");
                 return Ok(method);
             }
@@ -185,7 +230,7 @@ impl Il2CppClass {
             } {
                 (mi, None) => {
                     #[cfg(feature = "cache")]
-                    cache::METHOD_CACHE.with(move |c| c.borrow_mut().insert(key.into(), mi));
+                    cache::STATIC_METHODS.insert(key.into(), mi);
 
                     return Ok(mi);
                 }
@@ -196,8 +241,237 @@ impl Il2CppClass {
         Err(FindMethodError::None)
     }
 
+    /// Find a method belonging to the class or its parents by name with type
+    /// checking, returning a detailed error on failure
+    pub fn try_find_method<A, R, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<&'static MethodInfo, Il2CppError<'static>>
+    where
+        A: Arguments<N>,
+        R: Returned,
+    {
+        A::resolve()?;
+        R::resolve()?;
+        self.find_method::<A, R, N>(name).map_err(|e| {
+            self.find_method_error(e, name, |mi| A::matches(mi) && R::matches(mi.return_ty()))
+        })
+    }
+
+    /// Find a `static` method belonging to the class by name with type
+    /// checking, returning a detailed error on failure
+    pub fn try_find_static_method<A, R, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<&'static MethodInfo, Il2CppError<'static>>
+    where
+        A: Arguments<N>,
+        R: Returned,
+    {
+        A::resolve()?;
+        R::resolve()?;
+        self.find_static_method::<A, R, N>(name).map_err(|e| {
+            self.find_method_error(e, name, |mi| {
+                mi.is_static() && A::matches(mi) && R::matches(mi.return_ty())
+            })
+        })
+    }
+
+    /// Find a generic method belonging to the class or its parents by name,
+    /// instantiated with the given generic arguments, with type checking
+    #[crate::instrument(level = "debug")]
+    pub fn find_generic_method<G, A, R, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<&'static MethodInfo, FindMethodError>
+    where
+        G: Generics + 'static,
+        A: Arguments<N>,
+        R: Returned,
+    {
+        #[cfg(feature = "cache")]
+        let key = {
+            let key = cache::MemberKey {
+                class: self as *const Self as usize,
+                name: name.into(),
+                ty: Some(std::any::TypeId::of::<fn(Self, G, A::Type) -> R::Type>()),
+            };
+            if let Some(method) = cache::METHODS.get(&key) {
+                debug!("cache hit");
+                return Ok(method);
+            }
+            debug!("cache miss");
+            key
+        };
+
+        let method = self.find_generic_method_by::<G, N, _>(name, |mi| {
+            A::matches(mi) && R::matches(mi.return_ty())
+        })?;
+
+        #[cfg(feature = "cache")]
+        cache::METHODS.insert(key.into(), method);
+
+        Ok(method)
+    }
+
+    /// Find a `static` generic method belonging to the class or its parents by
+    /// name, instantiated with the given generic arguments, with type checking
+    #[crate::instrument(level = "debug")]
+    pub fn find_static_generic_method<G, A, R, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<&'static MethodInfo, FindMethodError>
+    where
+        G: Generics + 'static,
+        A: Arguments<N>,
+        R: Returned,
+    {
+        #[cfg(feature = "cache")]
+        let key = {
+            let key = cache::MemberKey {
+                class: self as *const Self as usize,
+                name: name.into(),
+                ty: Some(std::any::TypeId::of::<fn((), G, A::Type) -> R::Type>()),
+            };
+            if let Some(method) = cache::STATIC_METHODS.get(&key) {
+                debug!("cache hit");
+                return Ok(method);
+            }
+            debug!("cache miss");
+            key
+        };
+
+        let method = self.find_generic_method_by::<G, N, _>(name, |mi| {
+            mi.is_static() && A::matches(mi) && R::matches(mi.return_ty())
+        })?;
+
+        #[cfg(feature = "cache")]
+        cache::STATIC_METHODS.insert(key.into(), method);
+
+        Ok(method)
+    }
+
+    /// Find a generic method belonging to the class or its parents by name,
+    /// instantiated with the given generic arguments, with type checking,
+    /// returning a detailed error on failure
+    pub fn try_find_generic_method<G, A, R, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<&'static MethodInfo, Il2CppError<'static>>
+    where
+        G: Generics + 'static,
+        A: Arguments<N>,
+        R: Returned,
+    {
+        G::resolve()?;
+        A::resolve()?;
+        R::resolve()?;
+        self.find_generic_method::<G, A, R, N>(name).map_err(|e| {
+            self.find_method_error(e, name, |mi| {
+                instantiate::<G, N>(mi)
+                    .is_some_and(|mi| A::matches(mi) && R::matches(mi.return_ty()))
+            })
+        })
+    }
+
+    /// Find a `static` generic method belonging to the class or its parents by
+    /// name, instantiated with the given generic arguments, with type checking,
+    /// returning a detailed error on failure
+    pub fn try_find_static_generic_method<G, A, R, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<&'static MethodInfo, Il2CppError<'static>>
+    where
+        G: Generics + 'static,
+        A: Arguments<N>,
+        R: Returned,
+    {
+        G::resolve()?;
+        A::resolve()?;
+        R::resolve()?;
+        self.find_static_generic_method::<G, A, R, N>(name)
+            .map_err(|e| {
+                self.find_method_error(e, name, |mi| {
+                    instantiate::<G, N>(mi).is_some_and(|mi| {
+                        mi.is_static() && A::matches(mi) && R::matches(mi.return_ty())
+                    })
+                })
+            })
+    }
+
+    /// Instantiates the generic methods with the given name and finds the one
+    /// that matches, looking through the class hierarchy
+    fn find_generic_method_by<G, const N: usize, F>(
+        &self,
+        name: &str,
+        matches: F,
+    ) -> Result<&'static MethodInfo, FindMethodError>
+    where
+        G: Generics + 'static,
+        F: Fn(&MethodInfo) -> bool,
+    {
+        for c in self.hierarchy() {
+            let mut matching = c
+                .methods()
+                .iter()
+                .filter(|mi| mi.name() == name)
+                .filter_map(|mi| instantiate::<G, N>(mi))
+                .filter(|mi| matches(mi));
+
+            match match matching.next() {
+                // If we have no matches, we continue to the parent
+                None => continue,
+                Some(mi) => (mi, matching.next()),
+            } {
+                (mi, None) => return Ok(mi),
+                _ => return Err(FindMethodError::Many),
+            }
+        }
+
+        Err(FindMethodError::None)
+    }
+
+    fn find_method_error<F>(
+        &self,
+        error: FindMethodError,
+        name: &str,
+        matches: F,
+    ) -> Il2CppError<'static>
+    where
+        F: Fn(&MethodInfo) -> bool,
+    {
+        let class = self.to_string();
+        let name = name.to_owned();
+        match error {
+            FindMethodError::None => Il2CppError::MethodNotFound { class, name },
+            FindMethodError::Many => {
+                let candidates = self
+                    .hierarchy()
+                    .map(|c| {
+                        c.methods()
+                            .iter()
+                            .filter(|mi| mi.name() == name && matches(mi))
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                    })
+                    .find(|c| !c.is_empty())
+                    .unwrap_or_default();
+                Il2CppError::AmbiguousMethod {
+                    class,
+                    name,
+                    candidates,
+                }
+            }
+        }
+    }
+
     /// Find a method belonging to the class or its parents by name with type
     /// checking from a callee perspective
+    ///
+    /// The name can include generic arguments, such as
+    /// `GetComponent<UnityEngine.Transform>`, to find an instantiation of a
+    /// generic method. See [`find_instantiation`](Self::find_instantiation)
+    /// for how they are written.
     #[crate::instrument(level = "debug")]
     pub fn find_method_callee<T, P, R>(
         &self,
@@ -208,13 +482,15 @@ impl Il2CppClass {
         P: Parameters,
         R: Return,
     {
+        // A signature using a class which doesn't exist can't match anything
+        if T::resolve().and(P::resolve()).and(R::resolve()).is_err() {
+            return Err(FindMethodError::None);
+        }
+
         let mut matching = self
-            .methods()
-            .iter()
-            .filter(|mi| {
-                mi.name() == name && T::matches(mi) && P::matches(mi) && R::matches(mi.return_ty())
-            })
-            .copied();
+            .methods_named(name)
+            .into_iter()
+            .filter(|mi| T::matches(mi) && P::matches(mi) && R::matches(mi.return_ty()));
 
         match (matching.next(), matching.next()) {
             (Some(mi), None) | (None, Some(mi)) => Ok(mi),
@@ -223,6 +499,45 @@ impl Il2CppClass {
         }
     }
 
+    /// Find a method belonging to the class by name with type checking from a
+    /// callee perspective, only considering overloads with the given parameter
+    /// and return type names
+    ///
+    /// This can be used to select between overloads which the Rust types alone
+    /// can't tell apart. Type names are matched using
+    /// [`Il2CppType::is_named`]. Like with
+    /// [`find_method_callee`](Self::find_method_callee), the name can include
+    /// generic arguments.
+    #[crate::instrument(level = "debug")]
+    pub fn find_method_callee_exact<T, P, R>(
+        &self,
+        name: &str,
+        parameters: &[&str],
+        return_ty: Option<&str>,
+    ) -> Result<&'static MethodInfo, FindMethodError>
+    where
+        T: ThisParameter,
+        P: Parameters,
+        R: Return,
+    {
+        if T::resolve().and(P::resolve()).and(R::resolve()).is_err() {
+            return Err(FindMethodError::None);
+        }
+
+        let mut matching = self.methods_named(name).into_iter().filter(|mi| {
+            mi.has_signature(parameters, return_ty)
+                && T::matches(mi)
+                && P::matches(mi)
+                && R::matches(mi.return_ty())
+        });
+
+        match (matching.next(), matching.next()) {
+            (Some(mi), None) => Ok(mi),
+            (Some(_), Some(_)) => Err(FindMethodError::Many),
+            (None, _) => Err(FindMethodError::None),
+        }
+    }
+
     /// Find a method belonging to the class or its parents by name and
     /// parameter count, without type checking
     pub fn find_method_unchecked(
@@ -253,49 +568,234 @@ impl Il2CppClass {
     /// Find a field belonging to the class or its parents by name
     #[crate::instrument(level = "debug")]
     pub fn find_field(&self, name: &str) -> Option<&FieldInfo> {
+        #[cfg(feature = "cache")]
+        let key = {
+            let key = cache::MemberKey {
+                class: self as *const Self as usize,
+                name: name.into(),
+                ty: None,
+            };
+            if let Some(field) = cache::FIELDS.get(&key) {
+                debug!("cache hit");
+                return Some(field);
+            }
+            debug!("cache miss");
+            key
+        };
+
         for c in self.hierarchy() {
-            let mut matching = c.fields().iter().filter(|fi| fi.name() == name);
+            // If we have no matches, we continue to the parent
+            for fi in c.fields() {
+                if fi.name() != name {
+                    continue;
+                }
+                let fi: &FieldInfo = fi;
 
-            match matching.next() {
-                // If we have no matches, we continue to the parent
-                None => continue,
-                Some(fi) => return Some(fi),
+                // Fields live as long as their class, which is never freed
+                #[cfg(feature = "cache")]
+                cache::FIELDS.insert(key.into(), unsafe { &*(fi as *const FieldInfo) });
+
+                return Some(fi);
             }
         }
 
         None
     }
 
+    /// Find a field belonging to the class or its parents by name, returning a
+    /// detailed error on failure
+    pub fn try_find_field(&self, name: &str) -> Result<&FieldInfo, Il2CppError<'static>> {
+        self.find_field(name)
+            .ok_or_else(|| Il2CppError::FieldNotFound {
+                class: self.to_string(),
+                name: name.to_owned(),
+            })
+    }
+
+    /// Loads a value from a static field of the class or its parents with the
+    /// given name, with type checking
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the given field can't be found, if it isn't
+    /// static or if its type doesn't match
+    pub fn load_static<T>(&self, field: &str) -> T::Held<'static>
+    where
+        T: Type,
+    {
+        match self.try_load_static::<T>(field) {
+            Ok(value) => value,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Loads a value from a static field of the class or its parents with the
+    /// given name, with type checking, returning an error if the field can't
+    /// be found, if it isn't static or if its type doesn't match
+    pub fn try_load_static<T>(&self, field: &str) -> Result<T::Held<'static>, Il2CppError<'static>>
+    where
+        T: Type,
+    {
+        self.try_find_field(field)?.try_load_static::<T>()
+    }
+
+    /// Stores a given value into a static field of the class or its parents
+    /// with the given name, with type checking
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the given field can't be found, if it isn't
+    /// static or if its type doesn't match
+    pub fn store_static<A>(&self, field: &str, value: A)
+    where
+        A: Argument,
+    {
+        if let Err(e) = self.try_store_static(field, value) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Stores a given value into a static field of the class or its parents
+    /// with the given name, with type checking, returning an error if the
+    /// field can't be found, if it isn't static or if its type doesn't match
+    pub fn try_store_static<A>(&self, field: &str, value: A) -> Result<(), Il2CppError<'static>>
+    where
+        A: Argument,
+    {
+        self.try_find_field(field)?.try_store_static(value)
+    }
+
+    /// Find a property belonging to the class or its parents by name
+    #[crate::instrument(level = "debug")]
+    pub fn find_property(&self, name: &str) -> Option<&PropertyInfo> {
+        self.hierarchy()
+            .flat_map(|c| c.properties())
+            .find(|pi| pi.name() == name)
+    }
+
+    /// Find a property belonging to the class or its parents by name,
+    /// returning a detailed error on failure
+    pub fn try_find_property(&self, name: &str) -> Result<&PropertyInfo, Il2CppError<'static>> {
+        self.find_property(name)
+            .ok_or_else(|| Il2CppError::PropertyNotFound {
+                class: self.to_string(),
+                name: name.to_owned(),
+            })
+    }
+
+    /// Find an event belonging to the class or its parents by name
+    #[crate::instrument(level = "debug")]
+    pub fn find_event(&self, name: &str) -> Option<&EventInfo> {
+        self.hierarchy()
+            .flat_map(|c| c.events())
+            .find(|ei| ei.name() == name)
+    }
+
+    /// Find an event belonging to the class or its parents by name, returning
+    /// a detailed error on failure
+    pub fn try_find_event(&self, name: &str) -> Result<&EventInfo, Il2CppError<'static>> {
+        self.find_event(name)
+            .ok_or_else(|| Il2CppError::EventNotFound {
+                class: self.to_string(),
+                name: name.to_owned(),
+            })
+    }
+
     /// Instanciates a generic class template with the provided generic
     /// arguments
     pub fn make_generic<G>(&self) -> Result<Option<&'static Self>, &mut Il2CppException>
     where
-        G: Generics,
+        G: Generics + 'static,
     {
+        #[cfg(feature = "cache")]
+        let key = {
+            let key = cache::GenericKey {
+                template: self as *const Self as usize,
+                generics: std::any::TypeId::of::<G>(),
+            };
+            if let Some(class) = cache::GENERICS.get(&key) {
+                debug!("cache hit");
+                return Ok(Some(class));
+            }
+            debug!("cache miss");
+            key
+        };
+
         match self.ty().reflection_object().make_generic::<G>() {
-            Ok(Some(ty)) => Ok(Some(unsafe {
-                Self::wrap(raw::class_from_system_type(ty.raw()))
-            })),
+            Ok(Some(ty)) => {
+                let class = unsafe { Self::wrap(raw::class_from_system_type(ty.raw())) };
+
+                #[cfg(feature = "cache")]
+                cache::GENERICS.insert(key, class);
+
+                Ok(Some(class))
+            }
             Ok(None) => Ok(None),
             Err(e) => Err(e),
         }
     }
 
-    /// Instanciates an object of the class
+    /// Instanciates a generic class template with generic arguments which are
+    /// only known at runtime, returning `None` if it can't be instantiated
+    /// with them
+    fn make_generic_with(&self, generics: &[&Self]) -> Option<&'static Self> {
+        let generics = type_array_of(generics);
+        let ty = self
+            .ty()
+            .reflection_object()
+            .make_generic_from(generics)
+            .ok()??;
+        Some(unsafe { Self::wrap(raw::class_from_system_type(ty.raw())) })
+    }
+
+    /// Methods of the class with the given name, which can include generic
+    /// arguments to instantiate generic methods with
+    fn methods_named(&self, name: &str) -> Vec<&'static MethodInfo> {
+        let (name, generics) = split_generics(name);
+        let methods = self.methods().iter().filter(|mi| mi.name() == name);
+        if generics.is_empty() {
+            return methods.copied().collect();
+        }
+
+        match find_all_qualified(&generics) {
+            Some(generics) => methods
+                .filter_map(|mi| mi.make_generic_with(&generics))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Instanciates an object of the class, rooted by a [`GcHandle`]
     #[rustfmt::skip]
-    pub fn instantiate<T>(&self) -> &'static mut T
+    pub fn instantiate<T>(&self) -> GcHandle<T>
     where
         for<'a> T: Type<Held<'a> = Option<&'a mut T>>,
+    {
+        unsafe { GcHandle::new(self.instantiate_unrooted()) }
+    }
+
+    /// Instanciates an object of the class, without rooting it
+    ///
+    /// # Safety
+    /// Nothing prevents the object from being collected, so the returned
+    /// reference must not be used after the next garbage collection unless the
+    /// object is rooted by other means
+    #[rustfmt::skip]
+    pub unsafe fn instantiate_unrooted<'a, T>(&self) -> &'a mut T
+    where
+        for<'b> T: Type<Held<'b> = Option<&'b mut T>>,
     {
         assert!(T::class() == self);
-        unsafe {
-            let object = raw::object_new(self.raw());
-            transmute(object)
-        }
+        let object = raw::object_new(self.raw());
+        transmute(object)
     }
 
     /// Invokes the `static` method with the given name using the given
     /// arguments, with type checking
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if a matching method can't be found.
     pub fn invoke<A, R, const N: usize>(
         &self,
         name: &str,
@@ -305,12 +805,16 @@ impl Il2CppClass {
         A: Arguments<N>,
         R: Returned,
     {
-        let method = self.find_static_method::<A, R, N>(name).unwrap();
-        unsafe { method.invoke_unchecked((), args) }
+        self.try_invoke(name, args)
+            .map_err(Il2CppError::unwrap_exception)
     }
 
     /// Invokes the `static void` method with the given name using the given
     /// arguments, with type checking
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if a matching method can't be found.
     pub fn invoke_void<A, const N: usize>(
         &self,
         name: &str,
@@ -319,8 +823,77 @@ impl Il2CppClass {
     where
         A: Arguments<N>,
     {
-        let method = self.find_static_method::<A, (), N>(name).unwrap();
-        unsafe { method.invoke_unchecked((), args) }
+        self.try_invoke_void(name, args)
+            .map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Invokes the `static` method with the given name using the given
+    /// arguments, with type checking, returning an error if a matching method
+    /// can't be found
+    pub fn try_invoke<A, R, const N: usize>(
+        &self,
+        name: &str,
+        args: A,
+    ) -> Result<R, Il2CppError<'_>>
+    where
+        A: Arguments<N>,
+        R: Returned,
+    {
+        let method = self.try_find_static_method::<A, R, N>(name)?;
+        Ok(unsafe { method.invoke_unchecked((), args) }?)
+    }
+
+    /// Invokes the `static void` method with the given name using the given
+    /// arguments, with type checking, returning an error if a matching method
+    /// can't be found
+    pub fn try_invoke_void<A, const N: usize>(
+        &self,
+        name: &str,
+        args: A,
+    ) -> Result<(), Il2CppError<'_>>
+    where
+        A: Arguments<N>,
+    {
+        let method = self.try_find_static_method::<A, (), N>(name)?;
+        Ok(unsafe { method.invoke_unchecked((), args) }?)
+    }
+
+    /// Invokes the `static` generic method with the given name, instantiated
+    /// with the given generic arguments, using the given arguments, with type
+    /// checking
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if a matching method can't be found.
+    pub fn invoke_generic<G, A, R, const N: usize>(
+        &self,
+        name: &str,
+        args: A,
+    ) -> Result<R, &mut Il2CppException>
+    where
+        G: Generics + 'static,
+        A: Arguments<N>,
+        R: Returned,
+    {
+        self.try_invoke_generic::<G, A, R, N>(name, args)
+            .map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Invokes the `static` generic method with the given name, instantiated
+    /// with the given generic arguments, using the given arguments, with type
+    /// checking, returning an error if a matching method can't be found
+    pub fn try_invoke_generic<G, A, R, const N: usize>(
+        &self,
+        name: &str,
+        args: A,
+    ) -> Result<R, Il2CppError<'_>>
+    where
+        G: Generics + 'static,
+        A: Arguments<N>,
+        R: Returned,
+    {
+        let method = self.try_find_static_generic_method::<G, A, R, N>(name)?;
+        Ok(unsafe { method.invoke_unchecked((), args) }?)
     }
 
     /// Name of the class
@@ -339,10 +912,9 @@ impl Il2CppClass {
 
     /// Methods of the class
     pub fn methods(&self) -> &[&'static MethodInfo] {
-        let raw = self.raw();
-        let methods = raw.methods;
+        let methods = self.raw().methods;
         if !methods.is_null() {
-            unsafe { slice::from_raw_parts(methods as _, raw.method_count as _) }
+            unsafe { slice::from_raw_parts(methods as _, versioned!(self, method_count) as _) }
         } else {
             &[]
         }
@@ -350,10 +922,52 @@ impl Il2CppClass {
 
     /// Fields of the class
     pub fn fields(&self) -> FieldInfoSlice<'_> {
-        let raw = self.raw();
-        let fields = raw.fields;
-        if !fields.is_null() {
-            unsafe { slice::from_raw_parts(fields as _, raw.field_count as _) }
+        let fields = self.raw().fields;
+        if fields.is_null() {
+            return Default::default();
+        }
+        let count = versioned!(self, field_count) as usize;
+
+        #[cfg(not(feature = "dynamic"))]
+        {
+            unsafe { slice::from_raw_parts(fields as _, count) }
+        }
+
+        #[cfg(feature = "dynamic")]
+        {
+            match unity_version() {
+                UnityVersion::Unity2018 => {
+                    unsafe { slice::from_raw_parts(fields as *const &FieldInfo, count) }.to_vec()
+                }
+                UnityVersion::Unity2019
+                | UnityVersion::Unity2020
+                | UnityVersion::Unity2021
+                | UnityVersion::Unity2022 => {
+                    let fields =
+                        unsafe { slice::from_raw_parts(fields as *const FieldInfo, count) };
+                    fields.iter().collect()
+                }
+            }
+        }
+    }
+
+    /// Properties of the class
+    pub fn properties(&self) -> &[PropertyInfo] {
+        let properties = self.raw().properties;
+        if !properties.is_null() {
+            unsafe {
+                slice::from_raw_parts(properties.cast(), versioned!(self, property_count) as _)
+            }
+        } else {
+            &[]
+        }
+    }
+
+    /// Events of the class
+    pub fn events(&self) -> &[EventInfo] {
+        let events = self.raw().events;
+        if !events.is_null() {
+            unsafe { slice::from_raw_parts(events.cast(), versioned!(self, event_count) as _) }
         } else {
             &[]
         }
@@ -373,10 +987,11 @@ impl Il2CppClass {
 
     /// Interfaces this class implements
     pub fn implemented_interfaces(&self) -> &[&Self] {
-        let raw = self.raw();
-        let interfaces = raw.implementedInterfaces;
+        let interfaces = self.raw().implementedInterfaces;
         if !interfaces.is_null() {
-            unsafe { slice::from_raw_parts(interfaces as _, raw.interfaces_count as _) }
+            unsafe {
+                slice::from_raw_parts(interfaces as _, versioned!(self, interfaces_count) as _)
+            }
         } else {
             &[]
         }
@@ -384,8 +999,10 @@ impl Il2CppClass {
 
     /// Nested types of the class
     pub fn nested_types(&self) -> &[&Self] {
-        let raw = self.raw();
-        unsafe { slice::from_raw_parts(raw.nestedTypes as _, raw.nested_type_count as _) }
+        let nested_types = self.raw().nestedTypes;
+        unsafe {
+            slice::from_raw_parts(nested_types as _, versioned!(self, nested_type_count) as _)
+        }
     }
 
     /// Whether the class is assignable from `other`
@@ -393,6 +1010,17 @@ impl Il2CppClass {
         unsafe { raw::class_is_assignable_from(self.raw(), other.raw()) }
     }
 
+    /// Whether the class is an enum
+    pub fn is_enum(&self) -> bool {
+        versioned!(self, enumtype()) != 0
+    }
+
+    /// Class of the elements for array classes, of the underlying type for
+    /// enums and the class itself otherwise
+    pub fn element_class(&self) -> &Self {
+        unsafe { Self::wrap_ptr(self.raw().element_class) }.unwrap()
+    }
+
     /// [`Il2CppType`] of `this` for the class
     pub fn this_arg_ty(&self) -> &Il2CppType {
         unsafe { Il2CppType::wrap(&self.raw().this_arg) }
@@ -407,6 +1035,26 @@ impl Il2CppClass {
     pub fn ty(&self) -> &Il2CppType {
         unsafe { Il2CppType::wrap(raw::class_get_type(self.raw())) }
     }
+
+    #[cfg(feature = "dynamic")]
+    fn raw_2018(&self) -> &raw::unity2018::Il2CppClass {
+        unsafe { &*(self as *const Self).cast() }
+    }
+
+    #[cfg(feature = "dynamic")]
+    fn raw_2020(&self) -> &raw::unity2020::Il2CppClass {
+        unsafe { &*(self as *const Self).cast() }
+    }
+
+    #[cfg(feature = "dynamic")]
+    fn raw_2021(&self) -> &raw::unity2021::Il2CppClass {
+        unsafe { &*(self as *const Self).cast() }
+    }
+
+    #[cfg(feature = "dynamic")]
+    fn raw_2022(&self) -> &raw::unity2022::Il2CppClass {
+        unsafe { &*(self as *const Self).cast() }
+    }
 }
 
 /// Iterator over a class hierarchy
@@ -479,62 +1127,100 @@ pub enum FindMethodError {
     Many,
 }
 
-#[cfg(feature = "cache")]
-mod cache {
-    use std::any::TypeId;
-    use std::borrow::{Borrow, Cow};
-    use std::cell::RefCell;
-    use std::collections::HashMap;
-
-    #[derive(PartialEq, Eq, Hash)]
-    pub(super) struct ClassCacheKey<'a> {
-        pub(super) namespace: Cow<'a, str>,
-        pub(super) name: Cow<'a, str>,
-    }
-
-    #[derive(PartialEq, Eq, Hash)]
-    pub(super) struct StaticClassCacheKey(ClassCacheKey<'static>);
-
-    impl<'a> From<ClassCacheKey<'a>> for StaticClassCacheKey {
-        fn from(ClassCacheKey { namespace, name }: ClassCacheKey<'a>) -> Self {
-            let namespace = namespace.into_owned().into();
-            let name = name.into_owned().into();
-            Self(ClassCacheKey { namespace, name })
+/// Splits a name such as ``Dictionary`2<System.String, System.Int32>`` into the
+/// name of the generic template and its generic arguments
+///
+/// Names of compiler generated types and methods, such as `<Start>b__0`, also
+/// contain angle brackets but aren't generic instances.
+fn split_generics(name: &str) -> (&str, Vec<&str>) {
+    let inner = match name.strip_suffix('>') {
+        Some(inner) => inner,
+        None => return (name, Vec::new()),
+    };
+
+    let mut depth = 0usize;
+    let mut start = None;
+    for (i, c) in inner.char_indices().rev() {
+        match c {
+            '>' => depth += 1,
+            '<' if depth == 0 => {
+                start = Some(i);
+                break;
+            }
+            '<' => depth -= 1,
+            _ => (),
         }
     }
-
-    impl<'a> Borrow<ClassCacheKey<'a>> for StaticClassCacheKey {
-        fn borrow(&self) -> &ClassCacheKey<'a> {
-            &self.0
+    let start = match start {
+        Some(start) if start > 0 => start,
+        _ => return (name, Vec::new()),
+    };
+
+    let mut generics = Vec::new();
+    let mut depth = 0usize;
+    let mut arg_start = start + 1;
+    for (i, c) in inner.char_indices().skip_while(|&(i, _)| i <= start) {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                generics.push(inner[arg_start..i].trim());
+                arg_start = i + 1;
+            }
+            _ => (),
         }
     }
+    generics.push(inner[arg_start..].trim());
 
-    #[derive(PartialEq, Eq, Hash)]
-    pub(super) struct MethodCacheKey<'a> {
-        pub(super) class: ClassCacheKey<'a>,
-        pub(super) name: Cow<'a, str>,
-        pub(super) ty: TypeId,
-    }
-
-    #[derive(PartialEq, Eq, Hash)]
-    pub(super) struct StaticMethodCacheKey(MethodCacheKey<'static>);
+    (&name[..start], generics)
+}
 
-    impl<'a> From<MethodCacheKey<'a>> for StaticMethodCacheKey {
-        fn from(MethodCacheKey { class, name, ty }: MethodCacheKey<'a>) -> Self {
-            let class = StaticClassCacheKey::from(class).0;
-            let name = name.into_owned().into();
-            Self(MethodCacheKey { class, name, ty })
-        }
-    }
+/// Finds the classes with the given fully qualified names, which can include
+/// generic arguments
+fn find_all_qualified(names: &[&str]) -> Option<Vec<&'static Il2CppClass>> {
+    names
+        .iter()
+        .map(|name| {
+            let end = name.find('<').unwrap_or(name.len());
+            let (namespace, _) = name[..end].rsplit_once('.').unwrap_or(("", ""));
+            let name = name[namespace.len()..].trim_start_matches('.');
+            Il2CppClass::find_instantiation(namespace, name)
+        })
+        .collect()
+}
 
-    impl<'a> Borrow<MethodCacheKey<'a>> for StaticMethodCacheKey {
-        fn borrow(&self) -> &MethodCacheKey<'a> {
-            &self.0
-        }
+/// Instantiates a generic method template taking `N` parameters with the
+/// given generic arguments, if it can be
+fn instantiate<G, const N: usize>(method: &MethodInfo) -> Option<&'static MethodInfo>
+where
+    G: Generics + 'static,
+{
+    if method.generic_parameter_count() != G::COUNT || method.parameters().len() != N {
+        return None;
     }
+    // Instantiating with generic arguments which don't satisfy the constraints
+    // throws
+    method.make_generic::<G>().ok().flatten()
+}
 
-    thread_local! {
-        pub(super) static CLASS_CACHE: RefCell<HashMap<StaticClassCacheKey, &'static super::Il2CppClass>> = Default::default();
-        pub(super) static METHOD_CACHE: RefCell<HashMap<StaticMethodCacheKey, &'static super::MethodInfo>> = Default::default();
+#[cfg(test)]
+mod tests {
+    use super::split_generics;
+
+    #[test]
+    fn generic_names() {
+        assert_eq!(split_generics("List`1"), ("List`1", vec![]));
+        assert_eq!(split_generics("List`1<int>"), ("List`1", vec!["int"]));
+        assert_eq!(
+            split_generics("Dictionary`2<System.String, List`1<int>>"),
+            ("Dictionary`2", vec!["System.String", "List`1<int>"])
+        );
+        assert_eq!(
+            split_generics("Method<Dictionary`2<int, float>>"),
+            ("Method", vec!["Dictionary`2<int, float>"])
+        );
+
+        assert_eq!(split_generics("<Start>b__0"), ("<Start>b__0", vec![]));
+        assert_eq!(split_generics("<>c"), ("<>c", vec![]));
     }
 }
@@ -0,0 +1,715 @@
+use std::ffi::c_void;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::{align_of, needs_drop, size_of, transmute};
+use std::ops::{Deref, DerefMut};
+use std::ptr::{addr_of, addr_of_mut};
+use std::sync::Mutex;
+
+use crate::raw::{kInvalidIl2CppMethodSlot, METHOD_ATTRIBUTE_ABSTRACT, METHOD_ATTRIBUTE_VIRTUAL};
+#[cfg(feature = "dynamic")]
+use crate::runtime::{unity_version, UnityVersion};
+use crate::{
+    raw, Argument, Arguments, GcHandle, Il2CppClass, Il2CppError, Il2CppException, Il2CppObject,
+    Il2CppType, MethodInfo, Parameter, Parameters, Return, Returned, ThisArgument, ThisParameter,
+    Type, WrapRaw,
+};
+
+/// An il2cpp delegate taking parameters `P` and returning `R`
+///
+/// `P` is a tuple of the parameter types of the delegate, for example
+/// `(&mut Il2CppString, i32)` or `(i32,)`, and `()` for no parameters.
+///
+/// The signature is checked when creating a delegate from Rust or invoking it,
+/// but not when passing it around as a value, so a value of any delegate class
+/// can be used as an `Il2CppDelegate`.
+#[repr(transparent)]
+pub struct Il2CppDelegate<P, R = ()>(raw::Il2CppDelegate, PhantomData<fn(P) -> R>);
+
+impl<P, R> Il2CppDelegate<P, R> {
+    /// Creates a new delegate of the given class which calls a Rust closure,
+    /// boxed closure or function pointer such as `f as extern "C" fn(i32)`,
+    /// with type checking against the `Invoke` method of the class
+    ///
+    /// The closure is kept alive until the delegate is collected, and dropped
+    /// the next time a delegate is created from Rust or
+    /// [`drop_collected_callbacks`] is called.
+    pub fn from_callback<C>(
+        class: &Il2CppClass,
+        callback: C,
+    ) -> Result<GcHandle<Self>, Il2CppError<'static>>
+    where
+        C: Callback<P, R>,
+        P: Parameters,
+        R: Return,
+    {
+        let invoke = invoke_method(class)?;
+        P::resolve()?;
+        R::resolve()?;
+        if !P::matches(invoke) || !R::matches(invoke.return_ty()) {
+            let actual = format!("{} {}", R::type_name(), P::type_name());
+            return Err(Il2CppError::mismatch(invoke, actual));
+        }
+
+        let method_ptr: raw::Il2CppMethodPointer = unsafe { transmute(C::trampoline()) };
+        Ok(unsafe { Self::new(class, invoke, method_ptr, callback) })
+    }
+
+    /// Creates a new delegate of the given class which calls a raw function
+    ///
+    /// The function is called as a closed instance method, which means it
+    /// receives the target of the delegate, a managed array it should ignore,
+    /// then the actual types of the parameters and finally the [`MethodInfo`]
+    /// of the delegate.
+    ///
+    /// # Safety
+    /// The function must have a signature matching the `Invoke` method of the
+    /// class, as described above
+    pub unsafe fn from_method_pointer(
+        class: &Il2CppClass,
+        method_ptr: raw::Il2CppMethodPointer,
+    ) -> Result<GcHandle<Self>, Il2CppError<'static>> {
+        let invoke = invoke_method(class)?;
+        Ok(Self::new(class, invoke, method_ptr, ()))
+    }
+
+    /// Creates the delegate object, storing a pointer to `data` after the copy
+    /// of `invoke` the delegate calls
+    ///
+    /// Both are written to a managed byte array which is used as the target
+    /// of the delegate, so they are collected along with it, while `data` is
+    /// registered to be dropped once the delegate is collected.
+    unsafe fn new<D>(
+        class: &Il2CppClass,
+        invoke: &MethodInfo,
+        method_ptr: raw::Il2CppMethodPointer,
+        data: D,
+    ) -> GcHandle<Self>
+    where
+        D: Send + Sync + 'static,
+    {
+        // Array elements are only guaranteed to be 8-byte aligned
+        assert!(
+            align_of::<CallbackMethod<D>>() <= 8,
+            "callback method has an alignment over 8 bytes"
+        );
+
+        drop_collected_callbacks();
+        let data = Box::new(data);
+
+        let (info, invoker) = copy_invoke(invoke, method_ptr);
+
+        let storage = raw::array_new(<u8>::class().raw(), size_of::<CallbackMethod<D>>())
+            .expect("failed to allocate delegate storage");
+        let method = ((storage as *mut _ as isize) + (raw::kIl2CppSizeOfArray as isize))
+            as *mut CallbackMethod<D>;
+        method.write(CallbackMethod {
+            info,
+            callback: &*data,
+        });
+
+        let delegate: &'static mut raw::Il2CppDelegate = transmute(raw::object_new(class.raw()));
+        delegate.method_ptr = method_ptr;
+        delegate.invoke_impl = invoker;
+        delegate.method = addr_of!((*method).info).cast();
+        delegate.method_is_virtual = 0;
+        // The delegate is invoked as a closed instance delegate on every Unity
+        // version since it has a target
+        let target = addr_of_mut!(delegate.target).cast();
+        let storage = (storage as *mut raw::Il2CppArray).cast();
+        if raw::gc_wbarrier_set_field(&mut delegate.object, target, storage).is_none() {
+            *target = storage;
+        }
+
+        // Boxes of zero-sized values without destructors don't own anything
+        if size_of::<D>() == 0 && !needs_drop::<D>() {
+            Box::leak(data);
+        } else {
+            let handle = raw::gchandle_new_weakref(&delegate.object, false);
+            CALLBACKS.lock().unwrap().push(RegisteredCallback {
+                delegate: handle,
+                _callback: data,
+            });
+        }
+
+        GcHandle::new_unchecked(Self::wrap_mut(delegate))
+    }
+
+    /// Invokes the delegate with the given arguments, type checking against
+    /// the `Invoke` method of its class
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the provided types don't match the signature
+    /// of the delegate
+    pub fn invoke<const N: usize>(&mut self, args: P) -> Result<R, &mut Il2CppException>
+    where
+        P: Arguments<N>,
+        R: Returned,
+    {
+        self.try_invoke(args).map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Invokes the delegate with the given arguments, type checking against
+    /// the `Invoke` method of its class, returning an error if the provided
+    /// types don't match the signature of the delegate
+    pub fn try_invoke<const N: usize>(&mut self, args: P) -> Result<R, Il2CppError<'_>>
+    where
+        P: Arguments<N>,
+        R: Returned,
+    {
+        let invoke = invoke_method(self.class())?;
+        P::resolve()?;
+        R::resolve()?;
+        if !P::matches(invoke) {
+            return Err(Il2CppError::mismatch(invoke, P::type_name()));
+        }
+        if !R::matches(invoke.return_ty()) {
+            return Err(Il2CppError::mismatch(invoke.return_ty(), R::type_name()));
+        }
+        let this: &mut Il2CppObject = self;
+        Ok(unsafe { invoke.invoke_unchecked(this, args) }?)
+    }
+
+    /// [`MethodInfo`] the delegate calls
+    pub fn method(&self) -> &MethodInfo {
+        unsafe { MethodInfo::wrap_ptr(self.raw().method) }.unwrap()
+    }
+
+    /// Object the delegate calls its method on, if any
+    pub fn target(&self) -> Option<&Il2CppObject> {
+        unsafe { Il2CppObject::wrap_ptr(self.raw().target) }
+    }
+}
+
+/// Closures of delegates created from Rust whose delegates haven't been found
+/// to be collected yet
+static CALLBACKS: Mutex<Vec<RegisteredCallback>> = Mutex::new(Vec::new());
+
+/// Closure called by a delegate, along with a weak handle to the delegate
+struct RegisteredCallback {
+    delegate: u32,
+    _callback: Box<dyn Send + Sync>,
+}
+
+impl Drop for RegisteredCallback {
+    fn drop(&mut self) {
+        unsafe { raw::gchandle_free(self.delegate) };
+    }
+}
+
+/// Drops the closures of delegates created by
+/// [`Il2CppDelegate::from_callback`] which have been collected
+///
+/// This is done every time a delegate is created from Rust, so it only needs
+/// to be called to release the resources owned by closures sooner.
+pub fn drop_collected_callbacks() {
+    let collected = {
+        let mut callbacks = CALLBACKS.lock().unwrap();
+        let (alive, collected) = callbacks
+            .drain(..)
+            .partition(|c| unsafe { raw::gchandle_get_target(c.delegate) }.is_some());
+        *callbacks = alive;
+        collected
+    };
+    // Closures are dropped without holding the lock, in case their destructors
+    // create delegates
+    drop::<Vec<RegisteredCallback>>(collected);
+}
+
+/// `System.Delegate` class
+fn delegate_class() -> &'static Il2CppClass {
+    match try_delegate_class() {
+        Ok(class) => class,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// `System.Delegate` class, returning an error if it can't be found
+fn try_delegate_class() -> Result<&'static Il2CppClass, Il2CppError<'static>> {
+    Il2CppClass::find("System", "Delegate").ok_or_else(|| Il2CppError::ClassNotFound {
+        namespace: "System".to_owned(),
+        name: "Delegate".to_owned(),
+    })
+}
+
+/// `Invoke` method of a delegate class
+fn invoke_method(class: &Il2CppClass) -> Result<&'static MethodInfo, Il2CppError<'static>> {
+    let delegate_class = try_delegate_class()?;
+    if !delegate_class.is_assignable_from(class) {
+        return Err(Il2CppError::TypeMismatch {
+            expected: delegate_class.to_string(),
+            actual: class.to_string(),
+        });
+    }
+    class
+        .methods()
+        .iter()
+        .copied()
+        .find(|mi| mi.name() == "Invoke")
+        .ok_or_else(|| Il2CppError::MethodNotFound {
+            class: class.to_string(),
+            name: "Invoke".to_owned(),
+        })
+}
+
+unsafe impl<P, R> WrapRaw for Il2CppDelegate<P, R> {
+    type Raw = raw::Il2CppDelegate;
+}
+
+// Delegates can't implement `Type` since their parameters can borrow, as in
+// `Il2CppDelegate<(&mut Il2CppString, i32)>`, so they implement the type
+// checking traits directly, with `Il2CppDelegate<()>` as their normalized type.
+// Their signature is only checked when creating or invoking them, so it doesn't
+// matter that it is lost.
+
+unsafe impl<P, R> ThisArgument for Option<&mut Il2CppDelegate<P, R>> {
+    type Type = Il2CppDelegate<()>;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(method: &MethodInfo) -> bool {
+        delegate_class().is_assignable_from(method.class())
+    }
+
+    fn type_name() -> String {
+        delegate_class().ty().to_string()
+    }
+
+    fn invokable(&mut self) -> *mut c_void {
+        unsafe { transmute((self as *mut Self).read()) }
+    }
+}
+
+unsafe impl<P, R> ThisArgument for &mut Il2CppDelegate<P, R> {
+    type Type = Il2CppDelegate<()>;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(method: &MethodInfo) -> bool {
+        delegate_class().is_assignable_from(method.class())
+    }
+
+    fn type_name() -> String {
+        delegate_class().ty().to_string()
+    }
+
+    fn invokable(&mut self) -> *mut c_void {
+        (*self as *mut Il2CppDelegate<P, R>).cast()
+    }
+}
+
+unsafe impl<P, R> Argument for Option<&mut Il2CppDelegate<P, R>> {
+    type Type = Il2CppDelegate<()>;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        delegate_class().is_assignable_from(ty.class())
+    }
+
+    fn type_name() -> String {
+        delegate_class().ty().to_string()
+    }
+
+    fn with_invokable<O>(&mut self, f: impl FnOnce(*mut c_void) -> O) -> O {
+        f(unsafe { transmute::<Self, *mut c_void>((self as *mut Self).read()) })
+    }
+}
+
+unsafe impl<P, R> Argument for &mut Il2CppDelegate<P, R> {
+    type Type = Il2CppDelegate<()>;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        delegate_class().is_assignable_from(ty.class())
+    }
+
+    fn type_name() -> String {
+        delegate_class().ty().to_string()
+    }
+
+    fn with_invokable<O>(&mut self, f: impl FnOnce(*mut c_void) -> O) -> O {
+        f((*self as *mut Il2CppDelegate<P, R>).cast())
+    }
+}
+
+unsafe impl<P, R> Argument for &mut GcHandle<Il2CppDelegate<P, R>> {
+    type Type = Il2CppDelegate<()>;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        delegate_class().is_assignable_from(ty.class())
+    }
+
+    fn type_name() -> String {
+        delegate_class().ty().to_string()
+    }
+
+    fn with_invokable<O>(&mut self, f: impl FnOnce(*mut c_void) -> O) -> O {
+        f((&mut ***self as *mut Il2CppDelegate<P, R>).cast())
+    }
+}
+
+unsafe impl<P, R> Returned for Option<&mut Il2CppDelegate<P, R>> {
+    type Type = Il2CppDelegate<()>;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        delegate_class().is_assignable_from(ty.class())
+    }
+
+    fn type_name() -> String {
+        delegate_class().ty().to_string()
+    }
+
+    fn from_object(object: Option<&mut Il2CppObject>) -> Self {
+        unsafe { transmute(object) }
+    }
+}
+
+unsafe impl<P, R> Returned for &mut Il2CppDelegate<P, R> {
+    type Type = Il2CppDelegate<()>;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        delegate_class().is_assignable_from(ty.class())
+    }
+
+    fn type_name() -> String {
+        delegate_class().ty().to_string()
+    }
+
+    fn from_object(object: Option<&mut Il2CppObject>) -> Self {
+        unsafe { &mut *(object.unwrap() as *mut Il2CppObject).cast() }
+    }
+}
+
+unsafe impl<P, R> Returned for Option<GcHandle<Il2CppDelegate<P, R>>> {
+    type Type = Il2CppDelegate<()>;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        delegate_class().is_assignable_from(ty.class())
+    }
+
+    fn type_name() -> String {
+        delegate_class().ty().to_string()
+    }
+
+    fn from_object(object: Option<&mut Il2CppObject>) -> Self {
+        object.map(|o| unsafe { GcHandle::new_unchecked(&mut *(o as *mut Il2CppObject).cast()) })
+    }
+}
+
+unsafe impl<P, R> ThisParameter for Option<&mut Il2CppDelegate<P, R>> {
+    type Actual = Self;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(method: &MethodInfo) -> bool {
+        delegate_class().is_assignable_from(method.class())
+    }
+
+    fn from_actual(actual: Self::Actual) -> Self {
+        actual
+    }
+    fn into_actual(self) -> Self::Actual {
+        self
+    }
+}
+
+unsafe impl<P, R> ThisParameter for &mut Il2CppDelegate<P, R> {
+    type Actual = Option<Self>;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(method: &MethodInfo) -> bool {
+        delegate_class().is_assignable_from(method.class())
+    }
+
+    fn from_actual(actual: Self::Actual) -> Self {
+        actual.unwrap()
+    }
+    fn into_actual(self) -> Self::Actual {
+        Some(self)
+    }
+}
+
+unsafe impl<P, R> Parameter for Option<&mut Il2CppDelegate<P, R>> {
+    type Actual = Self;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        delegate_class().is_assignable_from(ty.class())
+    }
+
+    fn type_name() -> String {
+        delegate_class().ty().to_string()
+    }
+
+    fn from_actual(actual: Self::Actual) -> Self {
+        actual
+    }
+    fn into_actual(self) -> Self::Actual {
+        self
+    }
+}
+
+unsafe impl<P, R> Parameter for &mut Il2CppDelegate<P, R> {
+    type Actual = Option<Self>;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        delegate_class().is_assignable_from(ty.class())
+    }
+
+    fn type_name() -> String {
+        delegate_class().ty().to_string()
+    }
+
+    fn from_actual(actual: Self::Actual) -> Self {
+        actual.unwrap()
+    }
+    fn into_actual(self) -> Self::Actual {
+        Some(self)
+    }
+}
+
+unsafe impl<P, R> Return for Option<&mut Il2CppDelegate<P, R>> {
+    type Actual = Self;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        delegate_class().is_assignable_from(ty.class())
+    }
+
+    fn type_name() -> String {
+        delegate_class().ty().to_string()
+    }
+
+    fn into_actual(self) -> Self::Actual {
+        self
+    }
+    fn from_actual(actual: Self::Actual) -> Self {
+        actual
+    }
+}
+
+unsafe impl<P, R> Return for &mut Il2CppDelegate<P, R> {
+    type Actual = Option<Self>;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        try_delegate_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        delegate_class().is_assignable_from(ty.class())
+    }
+
+    fn type_name() -> String {
+        delegate_class().ty().to_string()
+    }
+
+    fn into_actual(self) -> Self::Actual {
+        Some(self)
+    }
+    fn from_actual(actual: Self::Actual) -> Self {
+        actual.unwrap()
+    }
+}
+
+impl<P, R> fmt::Debug for Il2CppDelegate<P, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Il2CppDelegate")
+            .field("method", self.method())
+            .finish()
+    }
+}
+
+impl<P, R> Deref for Il2CppDelegate<P, R> {
+    type Target = Il2CppObject;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { Il2CppObject::wrap(&self.raw().object) }
+    }
+}
+
+impl<P, R> DerefMut for Il2CppDelegate<P, R> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { Il2CppObject::wrap_mut(&mut self.raw_mut().object) }
+    }
+}
+
+/// Trait implemented by Rust closures and function pointers which can be
+/// called through C# delegates
+///
+/// # Note
+/// You should most likely not be implementing this trait yourself
+///
+/// # Safety
+/// The implementation must be correct
+pub unsafe trait Callback<P, R>: Send + Sync + 'static
+where
+    P: Parameters,
+    R: Return,
+{
+    /// Returns a function which can be used as the method pointer of a
+    /// delegate created by [`Il2CppDelegate::from_callback`]
+    fn trampoline() -> *const ();
+}
+
+/// Copies the `Invoke` method of a delegate class so that it calls
+/// `method_ptr` directly instead of through the vtable, returning the copy
+/// along with the invoker it uses
+unsafe fn copy_invoke(
+    invoke: &MethodInfo,
+    method_ptr: raw::Il2CppMethodPointer,
+) -> (MethodInfoCopy, raw::InvokerMethod) {
+    macro_rules! copy {
+        ($raw:expr $(, $virtual_ptr:ident)?) => {{
+            let mut info = *$raw;
+            info.methodPointer = method_ptr;
+            $(info.$virtual_ptr = method_ptr;)?
+            info.flags &= !((METHOD_ATTRIBUTE_VIRTUAL | METHOD_ATTRIBUTE_ABSTRACT) as u16);
+            info.slot = kInvalidIl2CppMethodSlot as _;
+            info
+        }};
+    }
+
+    #[cfg(any(feature = "unity2019", feature = "unity2018", feature = "unity2020"))]
+    {
+        let info = copy!(invoke.raw());
+        (info, info.invoker_method)
+    }
+
+    #[cfg(any(feature = "unity2021", feature = "unity2022"))]
+    {
+        let info = copy!(invoke.raw(), virtualMethodPointer);
+        (info, info.invoker_method)
+    }
+
+    #[cfg(feature = "dynamic")]
+    match unity_version() {
+        UnityVersion::Unity2018 | UnityVersion::Unity2019 | UnityVersion::Unity2020 => {
+            let info = copy!(invoke.raw());
+            (MethodInfoCopy { unity2019: info }, info.invoker_method)
+        }
+        UnityVersion::Unity2021 | UnityVersion::Unity2022 => {
+            let raw = &*(invoke as *const MethodInfo).cast::<raw::unity2021::MethodInfo>();
+            let info = copy!(raw, virtualMethodPointer);
+            let invoker =
+                transmute::<raw::unity2021::InvokerMethod, raw::InvokerMethod>(info.invoker_method);
+            (MethodInfoCopy { unity2021: info }, invoker)
+        }
+    }
+}
+
+#[cfg(not(feature = "dynamic"))]
+type MethodInfoCopy = raw::MethodInfo;
+
+/// Copy of a method, laid out like the `MethodInfo` of the Unity version of
+/// the runtime
+#[cfg(feature = "dynamic")]
+#[repr(C)]
+#[derive(Clone, Copy)]
+union MethodInfoCopy {
+    unity2019: raw::MethodInfo,
+    unity2021: raw::unity2021::MethodInfo,
+}
+
+/// Copy of the `Invoke` method of a delegate class followed by a pointer to
+/// the closure the delegate calls, if any
+#[repr(C)]
+struct CallbackMethod<F> {
+    info: MethodInfoCopy,
+    callback: *const F,
+}
+
+impl<F> CallbackMethod<F> {
+    /// Returns the closure stored after the given method
+    ///
+    /// # Safety
+    /// `method` must point to the `info` field of a `CallbackMethod<F>`
+    unsafe fn callback<'a>(method: *const raw::MethodInfo) -> &'a F {
+        &*(*method.cast::<Self>()).callback
+    }
+}
+
+unsafe impl<F, R> Callback<(), R> for F
+where
+    F: Fn() -> R + Send + Sync + 'static,
+    R: Return,
+{
+    fn trampoline() -> *const () {
+        unsafe extern "C" fn trampoline<F, R>(
+            _: *mut c_void,
+            method: *const raw::MethodInfo,
+        ) -> R::Actual
+        where
+            F: Fn() -> R + Send + Sync + 'static,
+            R: Return,
+        {
+            let callback = CallbackMethod::<F>::callback(method);
+            callback().into_actual()
+        }
+
+        trampoline::<F, R> as *const ()
+    }
+}
+
+quest_hook_proc_macros::impl_callbacks!(1..=16);
+
+unsafe impl<R> Callback<(), R> for extern "C" fn() -> R
+where
+    R: Return + 'static,
+{
+    fn trampoline() -> *const () {
+        unsafe extern "C" fn trampoline<R>(
+            _: *mut c_void,
+            method: *const raw::MethodInfo,
+        ) -> R::Actual
+        where
+            R: Return + 'static,
+        {
+            let callback = CallbackMethod::<extern "C" fn() -> R>::callback(method);
+            callback().into_actual()
+        }
+
+        trampoline::<R> as *const ()
+    }
+}
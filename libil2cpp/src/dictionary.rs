@@ -0,0 +1,363 @@
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
+use std::{fmt, ptr, slice};
+
+use crate::{
+    raw, Argument, GcHandle, Il2CppClass, Il2CppException, Il2CppObject, MethodInfo, ObjectExt,
+    Returned, Type, WrapRaw,
+};
+
+/// A C# `System.Collections.Generic.Dictionary<TKey, TValue>`
+#[repr(C)]
+pub struct Il2CppDictionary<K: Type, V: Type> {
+    object: Il2CppObject,
+    buckets: *mut raw::Il2CppArray,
+    entries: *mut raw::Il2CppArray,
+    count: i32,
+    version: i32,
+    free_list: i32,
+    free_count: i32,
+    comparer: *mut Il2CppObject,
+    keys: *mut Il2CppObject,
+    values: *mut Il2CppObject,
+    sync_root: *mut Il2CppObject,
+    marker: PhantomData<(K, V)>,
+}
+
+/// Element of the `entries` array of a dictionary
+#[repr(C)]
+struct Entry<'a, K: Type, V: Type> {
+    /// Lower 31 bits of the hash code of the key, or -1 if the entry is free
+    hash_code: i32,
+    next: i32,
+    key: K::Held<'a>,
+    value: V::Held<'a>,
+}
+
+crate::unsafe_impl_reference_type!(in crate for Il2CppDictionary<K, V> => System.Collections.Generic.Dictionary<K, V>);
+
+impl<K: Type, V: Type> Il2CppDictionary<K, V> {
+    /// Creates an empty dictionary, rooted by a [`GcHandle`]
+    pub fn new() -> GcHandle<Self> {
+        <Self as ObjectExt>::new(())
+    }
+
+    /// Creates an empty dictionary, without rooting it
+    ///
+    /// # Safety
+    /// Nothing prevents the dictionary from being collected, so the returned
+    /// reference must not be used after the next garbage collection unless the
+    /// dictionary is rooted by other means
+    pub unsafe fn new_unrooted<'a>() -> &'a mut Self {
+        <Self as ObjectExt>::new_unrooted(())
+    }
+
+    /// Number of entries in the dictionary
+    pub fn len(&self) -> usize {
+        (self.count - self.free_count) as usize
+    }
+
+    /// Whether the dictionary is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the value corresponding to the key
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the comparer of the dictionary throws an
+    /// exception
+    pub fn get<'a>(&self, key: K::Held<'a>) -> Option<&V::Held<'_>>
+    where
+        K::Held<'a>: Argument,
+    {
+        let index = self.find(key)?;
+        Some(&self.entries()[index].value)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the comparer of the dictionary throws an
+    /// exception
+    pub fn get_mut<'a>(&mut self, key: K::Held<'a>) -> Option<&mut V::Held<'_>>
+    where
+        K::Held<'a>: Argument,
+    {
+        let index = self.find(key)?;
+        Some(&mut self.entries_mut()[index].value)
+    }
+
+    /// Whether the dictionary contains a value for the key
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the comparer of the dictionary throws an
+    /// exception
+    pub fn contains_key<'a>(&self, key: K::Held<'a>) -> bool
+    where
+        K::Held<'a>: Argument,
+    {
+        self.find(key).is_some()
+    }
+
+    /// Inserts a key-value pair into the dictionary, replacing the previous
+    /// value for the key if there was one, or returns the exception thrown by
+    /// the dictionary, such as for `null` keys
+    pub fn insert<'a, 'b>(
+        &mut self,
+        key: K::Held<'a>,
+        value: V::Held<'b>,
+    ) -> Result<(), &mut Il2CppException>
+    where
+        K::Held<'a>: Argument,
+        V::Held<'b>: Argument,
+    {
+        self.invoke_void("set_Item", (key, value))
+    }
+
+    /// Removes a key from the dictionary, returning whether it was present, or
+    /// returns the exception thrown by the dictionary, such as for `null` keys
+    pub fn remove<'a>(&mut self, key: K::Held<'a>) -> Result<bool, &mut Il2CppException>
+    where
+        K::Held<'a>: Argument,
+    {
+        self.invoke("Remove", key)
+    }
+
+    /// Iterator over the key-value pairs of the dictionary, in no particular
+    /// order
+    pub fn iter(&self) -> DictionaryIter<'_, K, V> {
+        DictionaryIter {
+            entries: self.entries().iter(),
+        }
+    }
+
+    /// Index of the entry for the key in the `entries` array, found the same
+    /// way as the private `FindEntry` method does, through the
+    /// `IEqualityComparer<TKey>` of the dictionary
+    fn find<'a>(&self, mut key: K::Held<'a>) -> Option<usize>
+    where
+        K::Held<'a>: Argument,
+    {
+        key.with_invokable(|key_arg| {
+            let buckets = unsafe { self.buckets.as_ref() }?;
+            let comparer = unsafe { self.comparer.as_mut() }?;
+            if key_arg.is_null() {
+                return None;
+            }
+
+            let interface =
+                Il2CppClass::find_generic::<K>("System.Collections.Generic", "IEqualityComparer")
+                    .unwrap();
+            let comparer_method = |name: &str, count: usize| {
+                let method = interface.find_method_unchecked(name, count).unwrap();
+                let method =
+                    unsafe { raw::object_get_virtual_method(comparer.raw(), method.raw()) };
+                unsafe { MethodInfo::wrap(method.unwrap()) }
+            };
+            let get_hash_code = comparer_method("GetHashCode", 1);
+            let equals = comparer_method("Equals", 2);
+            let comparer = (comparer as *mut Il2CppObject).cast();
+
+            let hash_code = unsafe { get_hash_code.invoke_raw(comparer, &mut [key_arg]) };
+            let hash_code = match hash_code {
+                Ok(r) => {
+                    i32::from_object(r.map(|o| unsafe { Il2CppObject::wrap_mut(o) })) & 0x7fffffff
+                }
+                Err(e) => panic!("{}", unsafe { Il2CppException::wrap_mut(e) }),
+            };
+
+            let buckets = unsafe {
+                let ptr = ((buckets as *const _ as isize) + (raw::kIl2CppSizeOfArray as isize))
+                    as *const i32;
+                slice::from_raw_parts(ptr, raw::array_length(buckets) as usize)
+            };
+            let entries = self.entries();
+            let mut index = buckets[hash_code as usize % buckets.len()];
+            while let Ok(i) = usize::try_from(index) {
+                let entry = &entries[i];
+                if entry.hash_code == hash_code {
+                    // The key is only read to pass it to the comparer
+                    let mut entry_key = ManuallyDrop::new(unsafe {
+                        ptr::read((&entry.key as *const K::Held<'_>).cast::<K::Held<'a>>())
+                    });
+                    let equal = entry_key.with_invokable(|entry_key_arg| unsafe {
+                        equals.invoke_raw(comparer, &mut [entry_key_arg, key_arg])
+                    });
+                    match equal {
+                        Ok(r) => {
+                            if bool::from_object(r.map(|o| unsafe { Il2CppObject::wrap_mut(o) })) {
+                                return Some(i);
+                            }
+                        }
+                        Err(e) => panic!("{}", unsafe { Il2CppException::wrap_mut(e) }),
+                    }
+                }
+                index = entry.next;
+            }
+            None
+        })
+    }
+
+    /// Used part of the `entries` array, including free entries
+    fn entries(&self) -> &[Entry<'_, K, V>] {
+        match unsafe { self.entries.as_ref() } {
+            Some(entries) => {
+                let ptr = ((entries as *const _ as isize) + (raw::kIl2CppSizeOfArray as isize))
+                    as *const Entry<'_, K, V>;
+                unsafe { slice::from_raw_parts(ptr, self.count as usize) }
+            }
+            None => &[],
+        }
+    }
+
+    /// Mutable used part of the `entries` array, including free entries
+    fn entries_mut(&mut self) -> &mut [Entry<'_, K, V>] {
+        match unsafe { self.entries.as_mut() } {
+            Some(entries) => {
+                let ptr = ((entries as *mut _ as isize) + (raw::kIl2CppSizeOfArray as isize))
+                    as *mut Entry<'_, K, V>;
+                unsafe { slice::from_raw_parts_mut(ptr, self.count as usize) }
+            }
+            None => &mut [],
+        }
+    }
+}
+
+/// Iterator over the key-value pairs of an [`Il2CppDictionary`]
+pub struct DictionaryIter<'a, K: Type, V: Type> {
+    entries: slice::Iter<'a, Entry<'a, K, V>>,
+}
+
+impl<'a, K: Type, V: Type> Iterator for DictionaryIter<'a, K, V> {
+    type Item = (&'a K::Held<'a>, &'a V::Held<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries
+            .by_ref()
+            .find(|entry| entry.hash_code >= 0)
+            .map(|entry| (&entry.key, &entry.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.entries.size_hint().1)
+    }
+}
+
+impl<'a, K: Type, V: Type> IntoIterator for &'a Il2CppDictionary<K, V> {
+    type Item = (&'a K::Held<'a>, &'a V::Held<'a>);
+    type IntoIter = DictionaryIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: Type, V: Type> FromIterator<(K::Held<'a>, V::Held<'a>)>
+    for GcHandle<Il2CppDictionary<K, V>>
+where
+    K::Held<'a>: Argument,
+    V::Held<'a>: Argument,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K::Held<'a>, V::Held<'a>)>,
+    {
+        let mut dictionary = Il2CppDictionary::new();
+        for (key, value) in iter {
+            dictionary.insert(key, value).unwrap();
+        }
+        dictionary
+    }
+}
+
+impl<K: Type, V: Type> fmt::Debug for Il2CppDictionary<K, V>
+where
+    for<'a> K::Held<'a>: fmt::Debug,
+    for<'a> V::Held<'a>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Type, V: Type> Deref for Il2CppDictionary<K, V> {
+    type Target = Il2CppObject;
+
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<K: Type, V: Type> DerefMut for Il2CppDictionary<K, V> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::{fmt, Argument, GcHandle, Il2CppDictionary, PhantomData, Type};
+
+    use serde::de::{Deserialize, Deserializer, Error, MapAccess, Visitor};
+    use serde::ser::{Serialize, SerializeMap, Serializer};
+
+    struct DictionaryVisitor<K: Type, V: Type>(PhantomData<Il2CppDictionary<K, V>>);
+
+    impl<'de, K: Type, V: Type> Visitor<'de> for DictionaryVisitor<K, V>
+    where
+        K::Held<'de>: Deserialize<'de> + Argument,
+        V::Held<'de>: Deserialize<'de> + Argument,
+    {
+        type Value = GcHandle<Il2CppDictionary<K, V>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map of C# compatible keys and values")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut dictionary = Il2CppDictionary::new();
+            while let Some((key, value)) = map.next_entry()? {
+                dictionary.insert(key, value).map_err(Error::custom)?;
+            }
+            Ok(dictionary)
+        }
+    }
+
+    impl<'de, K: Type, V: Type> Deserialize<'de> for GcHandle<Il2CppDictionary<K, V>>
+    where
+        K::Held<'de>: Deserialize<'de> + Argument,
+        V::Held<'de>: Deserialize<'de> + Argument,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(DictionaryVisitor(PhantomData))
+        }
+    }
+
+    impl<K: Type, V: Type> Serialize for Il2CppDictionary<K, V>
+    where
+        for<'a> K::Held<'a>: Serialize,
+        for<'a> V::Held<'a>: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut map = serializer.serialize_map(Some(self.len()))?;
+            for (key, value) in self {
+                map.serialize_entry(key, value)?;
+            }
+            map.end()
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+
+use crate::Il2CppException;
+
+/// Possible errors when looking up or using il2cpp classes, methods, fields,
+/// properties and events
+#[derive(Debug, thiserror::Error)]
+pub enum Il2CppError<'a> {
+    /// No class with the given namespace and name was found
+    #[error("class {namespace}.{name} not found")]
+    ClassNotFound {
+        /// Namespace of the class
+        namespace: String,
+        /// Name of the class
+        name: String,
+    },
+
+    /// No method matching the given name and signature was found
+    #[error("no method {name} matching the signature found in {class}")]
+    MethodNotFound {
+        /// Class the method was looked up in
+        class: String,
+        /// Name of the method
+        name: String,
+    },
+
+    /// Multiple methods matching the given name and signature were found
+    #[error(
+        "multiple methods {name} matching the signature found in {class}: {}",
+        candidates.join(", ")
+    )]
+    AmbiguousMethod {
+        /// Class the method was looked up in
+        class: String,
+        /// Name of the method
+        name: String,
+        /// Signatures of the matching methods
+        candidates: Vec<String>,
+    },
+
+    /// No field with the given name was found
+    #[error("field {name} not found in {class}")]
+    FieldNotFound {
+        /// Class the field was looked up in
+        class: String,
+        /// Name of the field
+        name: String,
+    },
+
+    /// No property with the given name was found
+    #[error("property {name} not found in {class}")]
+    PropertyNotFound {
+        /// Class the property was looked up in
+        class: String,
+        /// Name of the property
+        name: String,
+    },
+
+    /// No event with the given name was found
+    #[error("event {name} not found in {class}")]
+    EventNotFound {
+        /// Class the event was looked up in
+        class: String,
+        /// Name of the event
+        name: String,
+    },
+
+    /// A static field was accessed as an instance field, or the other way
+    /// round
+    #[error(
+        "field {name} of {class} is {}",
+        if *is_static { "static" } else { "not static" }
+    )]
+    StaticMismatch {
+        /// Class the field belongs to
+        class: String,
+        /// Name of the field
+        name: String,
+        /// Whether the field is static
+        is_static: bool,
+    },
+
+    /// A value was stored into a literal field, which has no storage
+    #[error("field {name} of {class} is literal and can't be stored into")]
+    LiteralStore {
+        /// Class the field belongs to
+        class: String,
+        /// Name of the field
+        name: String,
+    },
+
+    /// A literal value was loaded from a field which isn't literal
+    #[error("field {name} of {class} is not literal")]
+    NotLiteral {
+        /// Class the field belongs to
+        class: String,
+        /// Name of the field
+        name: String,
+    },
+
+    /// A variant of a Rust enum has no matching value in the C# enum
+    #[error("variant {name} with value {value} not found in {class}")]
+    VariantNotFound {
+        /// C# enum the variant was looked up in
+        class: String,
+        /// Name of the Rust variant
+        name: String,
+        /// Value of the Rust variant
+        value: String,
+    },
+
+    /// A Rust type didn't match the C# type it was used as
+    #[error("type mismatch, expected {expected} but found {actual}")]
+    TypeMismatch {
+        /// Name of the expected C# type or signature
+        expected: String,
+        /// Name of the C# type or signature the Rust type actually used maps to
+        actual: String,
+    },
+
+    /// A managed exception was thrown
+    #[error("exception thrown: {0}")]
+    Exception(&'a mut Il2CppException),
+}
+
+impl<'a> Il2CppError<'a> {
+    /// Returns the thrown exception, panicking for any other error
+    ///
+    /// This is used to implement the panicking counterparts of the `try_`
+    /// methods, which only return exceptions.
+    pub(crate) fn unwrap_exception(self) -> &'a mut Il2CppException {
+        match self {
+            Self::Exception(e) => e,
+            e @ (Self::ClassNotFound { .. }
+            | Self::MethodNotFound { .. }
+            | Self::AmbiguousMethod { .. }
+            | Self::FieldNotFound { .. }
+            | Self::PropertyNotFound { .. }
+            | Self::EventNotFound { .. }
+            | Self::StaticMismatch { .. }
+            | Self::LiteralStore { .. }
+            | Self::NotLiteral { .. }
+            | Self::VariantNotFound { .. }
+            | Self::TypeMismatch { .. }) => panic!("{}", e),
+        }
+    }
+
+    /// Creates a [`TypeMismatch`](Self::TypeMismatch) error between the names
+    /// of the expected and actual C# types or signatures
+    pub(crate) fn mismatch(expected: &dyn fmt::Display, actual: String) -> Self {
+        Self::TypeMismatch {
+            expected: expected.to_string(),
+            actual,
+        }
+    }
+}
+
+impl<'a> From<&'a mut Il2CppException> for Il2CppError<'a> {
+    fn from(e: &'a mut Il2CppException) -> Self {
+        Self::Exception(e)
+    }
+}
@@ -0,0 +1,238 @@
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::fmt;
+
+use crate::{
+    raw, Arguments, Callback, GcHandle, Il2CppClass, Il2CppDelegate, Il2CppError, Il2CppException,
+    Il2CppObject, Il2CppType, MethodInfo, Parameters, Return, ThisArgument, WrapRaw,
+};
+
+/// Information about a C# event
+#[repr(transparent)]
+pub struct EventInfo(raw::EventInfo);
+
+unsafe impl Send for EventInfo {}
+unsafe impl Sync for EventInfo {}
+
+impl EventInfo {
+    /// Subscribes a Rust closure to the event, returning a handle to the
+    /// delegate calling it so it can later be passed to
+    /// [`remove`](Self::remove)
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the event has no add method or if the
+    /// provided types don't match its signature
+    pub fn subscribe<T, C, P, R>(
+        &self,
+        this: T,
+        callback: C,
+    ) -> Result<GcHandle<Il2CppDelegate<P, R>>, &mut Il2CppException>
+    where
+        T: ThisArgument,
+        C: Callback<P, R>,
+        P: Parameters,
+        R: Return,
+    {
+        self.try_subscribe(this, callback)
+            .map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Subscribes a Rust closure to the event, returning a handle to the
+    /// delegate calling it so it can later be passed to
+    /// [`remove`](Self::remove), or an error if the event has no add method or
+    /// if the provided types don't match its signature
+    pub fn try_subscribe<T, C, P, R>(
+        &self,
+        this: T,
+        callback: C,
+    ) -> Result<GcHandle<Il2CppDelegate<P, R>>, Il2CppError<'_>>
+    where
+        T: ThisArgument,
+        C: Callback<P, R>,
+        P: Parameters,
+        R: Return,
+    {
+        let mut delegate = Il2CppDelegate::from_callback(self.ty().class(), callback)?;
+        self.try_add(this, &mut delegate)?;
+        Ok(delegate)
+    }
+
+    /// Adds a handler to the event, with type checking
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the event has no add method or if the
+    /// provided types don't match its signature
+    pub fn add<T, P, R>(
+        &self,
+        this: T,
+        handler: &mut Il2CppDelegate<P, R>,
+    ) -> Result<(), &mut Il2CppException>
+    where
+        T: ThisArgument,
+    {
+        self.try_add(this, handler)
+            .map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Adds a handler to the event, with type checking, returning an error if
+    /// the event has no add method or if the provided types don't match its
+    /// signature
+    pub fn try_add<T, P, R>(
+        &self,
+        this: T,
+        handler: &mut Il2CppDelegate<P, R>,
+    ) -> Result<(), Il2CppError<'_>>
+    where
+        T: ThisArgument,
+    {
+        let adder = self.adder().ok_or_else(|| self.missing_accessor("add"))?;
+        self.call_accessor(adder, this, handler)
+    }
+
+    /// Removes a handler from the event, with type checking
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the event has no remove method or if the
+    /// provided types don't match its signature
+    pub fn remove<T, P, R>(
+        &self,
+        this: T,
+        handler: &mut Il2CppDelegate<P, R>,
+    ) -> Result<(), &mut Il2CppException>
+    where
+        T: ThisArgument,
+    {
+        self.try_remove(this, handler)
+            .map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Removes a handler from the event, with type checking, returning an
+    /// error if the event has no remove method or if the provided types don't
+    /// match its signature
+    pub fn try_remove<T, P, R>(
+        &self,
+        this: T,
+        handler: &mut Il2CppDelegate<P, R>,
+    ) -> Result<(), Il2CppError<'_>>
+    where
+        T: ThisArgument,
+    {
+        let remover = self
+            .remover()
+            .ok_or_else(|| self.missing_accessor("remove"))?;
+        self.call_accessor(remover, this, handler)
+    }
+
+    /// Raises the event with the given arguments, with type checking
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the event has no raise method or if the
+    /// provided types don't match its signature
+    pub fn raise<T, A, const N: usize>(&self, this: T, args: A) -> Result<(), &mut Il2CppException>
+    where
+        T: ThisArgument,
+        A: Arguments<N>,
+    {
+        self.try_raise(this, args)
+            .map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Raises the event with the given arguments, with type checking,
+    /// returning an error if the event has no raise method or if the provided
+    /// types don't match its signature
+    ///
+    /// C# compilers don't generate raise methods, so this only works for
+    /// events which define one explicitly.
+    pub fn try_raise<T, A, const N: usize>(&self, this: T, args: A) -> Result<(), Il2CppError<'_>>
+    where
+        T: ThisArgument,
+        A: Arguments<N>,
+    {
+        let raiser = self
+            .raiser()
+            .ok_or_else(|| self.missing_accessor("raise"))?;
+        raiser.try_invoke(this, args)
+    }
+
+    /// Name of the event
+    pub fn name(&self) -> Cow<'_, str> {
+        let name = self.raw().name;
+        assert!(!name.is_null());
+        unsafe { CStr::from_ptr(name) }.to_string_lossy()
+    }
+
+    /// Class the event is from
+    pub fn parent(&self) -> &Il2CppClass {
+        unsafe { Il2CppClass::wrap_ptr(self.raw().parent) }.unwrap()
+    }
+
+    /// Delegate type of the event
+    pub fn ty(&self) -> &Il2CppType {
+        unsafe { Il2CppType::wrap_ptr(self.raw().eventType) }.unwrap()
+    }
+
+    /// Add method of the event, if it has one
+    pub fn adder(&self) -> Option<&'static MethodInfo> {
+        unsafe { MethodInfo::wrap_ptr(self.raw().add) }
+    }
+
+    /// Remove method of the event, if it has one
+    pub fn remover(&self) -> Option<&'static MethodInfo> {
+        unsafe { MethodInfo::wrap_ptr(self.raw().remove) }
+    }
+
+    /// Raise method of the event, if it has one
+    pub fn raiser(&self) -> Option<&'static MethodInfo> {
+        unsafe { MethodInfo::wrap_ptr(self.raw().raise) }
+    }
+
+    /// Calls the add or remove method of the event, checking the class of the
+    /// handler at runtime since the signature of delegates is only checked
+    /// when creating or invoking them
+    fn call_accessor<T, P, R>(
+        &self,
+        accessor: &'static MethodInfo,
+        this: T,
+        handler: &mut Il2CppDelegate<P, R>,
+    ) -> Result<(), Il2CppError<'static>>
+    where
+        T: ThisArgument,
+    {
+        T::resolve()?;
+        if !T::matches(accessor) {
+            return Err(Il2CppError::mismatch(accessor.class(), T::type_name()));
+        }
+        if !self.ty().class().is_assignable_from(handler.class()) {
+            return Err(Il2CppError::TypeMismatch {
+                expected: self.ty().to_string(),
+                actual: handler.class().to_string(),
+            });
+        }
+        let handler: &mut Il2CppObject = handler;
+        Ok(unsafe { accessor.invoke_unchecked(this, handler) }?)
+    }
+
+    fn missing_accessor(&self, prefix: &str) -> Il2CppError<'static> {
+        Il2CppError::MethodNotFound {
+            class: self.parent().to_string(),
+            name: format!("{}_{}", prefix, self.name()),
+        }
+    }
+}
+
+unsafe impl WrapRaw for EventInfo {
+    type Raw = raw::EventInfo;
+}
+
+impl fmt::Debug for EventInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventInfo")
+            .field("name", &self.name())
+            .field("type", self.ty())
+            .finish()
+    }
+}
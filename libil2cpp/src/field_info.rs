@@ -3,7 +3,13 @@ use std::ffi::CStr;
 use std::fmt;
 use std::mem::MaybeUninit;
 
-use crate::{raw, Argument, Il2CppClass, Il2CppObject, Il2CppType, Type, WrapRaw};
+use crate::raw::{
+    FIELD_ATTRIBUTE_ASSEMBLY, FIELD_ATTRIBUTE_FAMILY, FIELD_ATTRIBUTE_FAM_AND_ASSEM,
+    FIELD_ATTRIBUTE_FAM_OR_ASSEM, FIELD_ATTRIBUTE_FIELD_ACCESS_MASK, FIELD_ATTRIBUTE_INIT_ONLY,
+    FIELD_ATTRIBUTE_LITERAL, FIELD_ATTRIBUTE_PRIVATE, FIELD_ATTRIBUTE_PUBLIC,
+    FIELD_ATTRIBUTE_STATIC, THREAD_STATIC_FIELD_OFFSET,
+};
+use crate::{raw, Argument, Il2CppClass, Il2CppError, Il2CppObject, Il2CppType, Type, WrapRaw};
 
 /// Information about a C# field
 #[repr(transparent)]
@@ -14,12 +20,36 @@ unsafe impl Sync for FieldInfo {}
 
 impl FieldInfo {
     /// Store a typechecked value into a field
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the type of the value doesn't match
     pub fn store<A>(&self, instance: &mut Il2CppObject, val: A)
     where
         A: Argument,
     {
-        assert!(A::matches(self.ty()));
+        if let Err(e) = self.try_store(instance, val) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Store a typechecked value into a field, returning an error if the type
+    /// of the value doesn't match
+    pub fn try_store<A>(
+        &self,
+        instance: &mut Il2CppObject,
+        val: A,
+    ) -> Result<(), Il2CppError<'static>>
+    where
+        A: Argument,
+    {
+        self.check_static(false)?;
+        A::resolve()?;
+        if !A::matches(self.ty()) {
+            return Err(Il2CppError::mismatch(self.ty(), A::type_name()));
+        }
         unsafe { self.store_unchecked(instance, val) };
+        Ok(())
     }
 
     /// Store a value into a field without type checking
@@ -30,16 +60,42 @@ impl FieldInfo {
     where
         A: Argument,
     {
-        raw::field_set_value(instance.raw_mut(), self.raw(), val.invokable());
+        val.with_invokable(|val| raw::field_set_value(instance.raw_mut(), self.raw(), val));
     }
 
     /// Load a typechecked value from a field
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the type of the field doesn't match
     pub fn load<'a, T>(&self, instance: &'a mut Il2CppObject) -> T::Held<'a>
     where
         T: Type,
     {
-        assert!(T::class().is_assignable_from(self.ty().class()));
-        unsafe { self.load_unchecked::<T>(instance) }
+        match self.try_load::<T>(instance) {
+            Ok(value) => value,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Load a typechecked value from a field, returning an error if the type of
+    /// the field doesn't match
+    pub fn try_load<'a, T>(
+        &self,
+        instance: &'a mut Il2CppObject,
+    ) -> Result<T::Held<'a>, Il2CppError<'static>>
+    where
+        T: Type,
+    {
+        self.check_static(false)?;
+        let class = T::try_class()?;
+        if !class.is_assignable_from(self.ty().class()) {
+            return Err(Il2CppError::TypeMismatch {
+                expected: class.to_string(),
+                actual: self.ty().to_string(),
+            });
+        }
+        Ok(unsafe { self.load_unchecked::<T>(instance) })
     }
 
     /// Load a value from a field without type checking
@@ -55,6 +111,202 @@ impl FieldInfo {
         val.assume_init()
     }
 
+    /// Store a typechecked value into a static field
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the field isn't static or if the type of the
+    /// value doesn't match
+    pub fn store_static<A>(&self, val: A)
+    where
+        A: Argument,
+    {
+        if let Err(e) = self.try_store_static(val) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Store a typechecked value into a static field, returning an error if
+    /// the field isn't static or if the type of the value doesn't match
+    pub fn try_store_static<A>(&self, val: A) -> Result<(), Il2CppError<'static>>
+    where
+        A: Argument,
+    {
+        self.check_static(true)?;
+        if self.is_literal() {
+            return Err(Il2CppError::LiteralStore {
+                class: self.parent().to_string(),
+                name: self.name().into_owned(),
+            });
+        }
+        A::resolve()?;
+        if !A::matches(self.ty()) {
+            return Err(Il2CppError::mismatch(self.ty(), A::type_name()));
+        }
+        unsafe { self.store_static_unchecked(val) };
+        Ok(())
+    }
+
+    /// Store a value into a static field without type checking, running the
+    /// static constructor of its class first if needed
+    ///
+    /// # Safety
+    /// To be safe, the field has to be static and not literal, and the provided
+    /// type has to match its signature
+    pub unsafe fn store_static_unchecked<A>(&self, mut val: A)
+    where
+        A: Argument,
+    {
+        raw::runtime_class_init(self.parent().raw());
+        val.with_invokable(|val| raw::field_static_set_value(self.raw(), val));
+    }
+
+    /// Load a typechecked value from a static field
+    ///
+    /// This also works for thread static fields, whose value is the one for the
+    /// current thread, and for literal fields, whose value is read from the
+    /// metadata
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the field isn't static or if its type doesn't
+    /// match
+    pub fn load_static<T>(&self) -> T::Held<'static>
+    where
+        T: Type,
+    {
+        match self.try_load_static::<T>() {
+            Ok(value) => value,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Load a typechecked value from a static field, returning an error if the
+    /// field isn't static or if its type doesn't match
+    pub fn try_load_static<T>(&self) -> Result<T::Held<'static>, Il2CppError<'static>>
+    where
+        T: Type,
+    {
+        self.check_static(true)?;
+        let class = T::try_class()?;
+        if !class.is_assignable_from(self.ty().class()) {
+            return Err(Il2CppError::TypeMismatch {
+                expected: class.to_string(),
+                actual: self.ty().to_string(),
+            });
+        }
+        Ok(unsafe { self.load_static_unchecked::<T>() })
+    }
+
+    /// Load a value from a static field without type checking, running the
+    /// static constructor of its class first if needed
+    ///
+    /// # Safety
+    /// To be safe, the field has to be static and the provided type has to
+    /// match its signature
+    pub unsafe fn load_static_unchecked<T>(&self) -> T::Held<'static>
+    where
+        T: Type,
+    {
+        raw::runtime_class_init(self.parent().raw());
+        let mut val: MaybeUninit<T::Held<'static>> = MaybeUninit::uninit();
+        raw::field_static_get_value(self.raw(), val.as_mut_ptr().cast());
+        val.assume_init()
+    }
+
+    /// Load the typechecked value of a literal (`const`) field from the
+    /// metadata
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the field isn't literal or if its type
+    /// doesn't match
+    pub fn load_literal<T>(&self) -> T::Held<'static>
+    where
+        T: Type,
+    {
+        match self.try_load_literal::<T>() {
+            Ok(value) => value,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Load the typechecked value of a literal (`const`) field from the
+    /// metadata, returning an error if the field isn't literal or if its type
+    /// doesn't match
+    pub fn try_load_literal<T>(&self) -> Result<T::Held<'static>, Il2CppError<'static>>
+    where
+        T: Type,
+    {
+        if !self.is_literal() {
+            return Err(Il2CppError::NotLiteral {
+                class: self.parent().to_string(),
+                name: self.name().into_owned(),
+            });
+        }
+        self.try_load_static::<T>()
+    }
+
+    /// Whether the field is static
+    pub fn is_static(&self) -> bool {
+        self.attrs() & FIELD_ATTRIBUTE_STATIC != 0
+    }
+
+    /// Whether the field is thread static, which means each thread has its
+    /// own value
+    pub fn is_thread_static(&self) -> bool {
+        self.raw().offset == THREAD_STATIC_FIELD_OFFSET
+    }
+
+    /// Whether the field is literal (`const`), which means its value lives in
+    /// the metadata and is inlined by the compiler
+    pub fn is_literal(&self) -> bool {
+        self.attrs() & FIELD_ATTRIBUTE_LITERAL != 0
+    }
+
+    /// Whether the field is init only (`readonly`)
+    pub fn is_init_only(&self) -> bool {
+        self.attrs() & FIELD_ATTRIBUTE_INIT_ONLY != 0
+    }
+
+    /// Accessibility of the field
+    pub fn accessibility(&self) -> Accessibility {
+        match self.attrs() & FIELD_ATTRIBUTE_FIELD_ACCESS_MASK {
+            FIELD_ATTRIBUTE_PRIVATE => Accessibility::Private,
+            FIELD_ATTRIBUTE_FAM_AND_ASSEM => Accessibility::ProtectedAndInternal,
+            FIELD_ATTRIBUTE_ASSEMBLY => Accessibility::Internal,
+            FIELD_ATTRIBUTE_FAMILY => Accessibility::Protected,
+            FIELD_ATTRIBUTE_FAM_OR_ASSEM => Accessibility::ProtectedOrInternal,
+            FIELD_ATTRIBUTE_PUBLIC => Accessibility::Public,
+            _ => Accessibility::CompilerControlled,
+        }
+    }
+
+    /// Whether the field is `public`
+    pub fn is_public(&self) -> bool {
+        self.accessibility() == Accessibility::Public
+    }
+
+    /// Whether the field is `private`
+    pub fn is_private(&self) -> bool {
+        self.accessibility() == Accessibility::Private
+    }
+
+    fn attrs(&self) -> u32 {
+        self.ty().attrs()
+    }
+
+    fn check_static(&self, is_static: bool) -> Result<(), Il2CppError<'static>> {
+        if self.is_static() == is_static {
+            return Ok(());
+        }
+        Err(Il2CppError::StaticMismatch {
+            class: self.parent().to_string(),
+            name: self.name().into_owned(),
+            is_static: !is_static,
+        })
+    }
+
     /// Name of the field
     pub fn name(&self) -> Cow<'_, str> {
         let name = self.raw().name;
@@ -82,6 +334,27 @@ impl fmt::Debug for FieldInfo {
         f.debug_struct("FieldInfo")
             .field("name", &self.name())
             .field("type", self.ty())
+            .field("static", &self.is_static())
+            .field("accessibility", &self.accessibility())
             .finish()
     }
 }
+
+/// Accessibility of a C# member
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Accessibility {
+    /// Member which can't be referenced
+    CompilerControlled,
+    /// `private` member
+    Private,
+    /// `private protected` member
+    ProtectedAndInternal,
+    /// `internal` member
+    Internal,
+    /// `protected` member
+    Protected,
+    /// `protected internal` member
+    ProtectedOrInternal,
+    /// `public` member
+    Public,
+}
@@ -0,0 +1,189 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+
+use crate::{raw, Type};
+
+/// Strong GC handle keeping a managed object alive for as long as it exists
+///
+/// References to the object can only be borrowed from the handle, so they can't
+/// outlive it.
+///
+/// Handles can be sent to other threads, but dereferencing them panics on
+/// threads which aren't attached to the runtime.
+pub struct GcHandle<T> {
+    handle: u32,
+    object: NonNull<T>,
+}
+
+// The GC handle table is thread-safe and handles are valid in the whole
+// process, while access to the object is guarded by the borrow checker and
+// checked to happen on an attached thread
+unsafe impl<T> Send for GcHandle<T> {}
+
+impl<T> GcHandle<T>
+where
+    T: for<'a> Type<Held<'a> = Option<&'a mut T>>,
+{
+    /// Creates a new strong handle to the object, preventing it from being
+    /// collected
+    ///
+    /// # Safety
+    /// The handle gives mutable access to the object, so no other reference to
+    /// it, including through another handle, may be used while the handle
+    /// exists
+    pub unsafe fn new(object: &mut T) -> Self {
+        Self::new_unchecked(object)
+    }
+}
+
+impl<T> GcHandle<T> {
+    /// Creates a new strong handle to a managed object whose type doesn't
+    /// implement [`Type`], such as a delegate
+    ///
+    /// # Safety
+    /// `object` must point to a managed object, and the same requirements as
+    /// for [`GcHandle::new`] apply
+    pub(crate) unsafe fn new_unchecked(object: &mut T) -> Self {
+        let handle = raw::gchandle_new(as_raw(object), false);
+        Self {
+            handle,
+            object: NonNull::from(object),
+        }
+    }
+
+    /// Creates a new weak handle to the object of this handle
+    ///
+    /// This is an associated function so it can't shadow methods of the
+    /// object, and needs to be called as `GcHandle::downgrade(&handle)`.
+    pub fn downgrade(this: &Self) -> WeakGcHandle<T> {
+        let handle = unsafe { raw::gchandle_new_weakref(as_raw(this.object.as_ptr()), false) };
+        WeakGcHandle {
+            handle,
+            marker: PhantomData,
+        }
+    }
+
+    /// Raw il2cpp handle, which also needs to be called as
+    /// `GcHandle::raw(&handle)`
+    pub fn raw(this: &Self) -> u32 {
+        this.handle
+    }
+}
+
+impl<T> Deref for GcHandle<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        assert_attached();
+        unsafe { self.object.as_ref() }
+    }
+}
+
+impl<T> DerefMut for GcHandle<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        assert_attached();
+        unsafe { self.object.as_mut() }
+    }
+}
+
+impl<T> Drop for GcHandle<T> {
+    fn drop(&mut self) {
+        unsafe { raw::gchandle_free(self.handle) };
+    }
+}
+
+impl<T> fmt::Debug for GcHandle<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GcHandle")
+            .field("handle", &self.handle)
+            .field("object", &**self)
+            .finish()
+    }
+}
+
+/// Weak GC handle to a managed object, which doesn't prevent it from being
+/// collected
+pub struct WeakGcHandle<T> {
+    handle: u32,
+    marker: PhantomData<NonNull<T>>,
+}
+
+unsafe impl<T> Send for WeakGcHandle<T> {}
+unsafe impl<T> Sync for WeakGcHandle<T> {}
+
+impl<T> WeakGcHandle<T>
+where
+    T: for<'a> Type<Held<'a> = Option<&'a mut T>>,
+{
+    /// Creates a new weak handle to the object
+    pub fn new(object: &mut T) -> Self {
+        let handle = unsafe { raw::gchandle_new_weakref(as_raw(object), false) };
+        Self {
+            handle,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> WeakGcHandle<T> {
+    /// Creates a new strong handle to the object if it hasn't been collected
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the current thread isn't attached to the
+    /// runtime
+    ///
+    /// # Safety
+    /// The same requirements as for [`GcHandle::new`] apply, so no other
+    /// strong handle created from this one may be alive
+    pub unsafe fn upgrade(&self) -> Option<GcHandle<T>> {
+        // The object can't be collected while we hold a pointer to it on the
+        // stack, since the GC scans it conservatively
+        assert_attached();
+        let object = raw::gchandle_get_target(self.handle)?;
+        let object = &mut *(object as *mut raw::Il2CppObject).cast::<T>();
+        Some(GcHandle::new_unchecked(object))
+    }
+
+    /// Raw il2cpp handle
+    pub fn raw(&self) -> u32 {
+        self.handle
+    }
+}
+
+impl<T> Drop for WeakGcHandle<T> {
+    fn drop(&mut self) {
+        unsafe { raw::gchandle_free(self.handle) };
+    }
+}
+
+impl<T> fmt::Debug for WeakGcHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakGcHandle")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+/// Panics if the current thread isn't attached to the runtime, since managed
+/// objects can't be accessed from other threads
+fn assert_attached() {
+    // The check is skipped on Unity versions which don't export
+    // `il2cpp_thread_current`
+    if let Some(None) = unsafe { raw::thread_current() } {
+        panic!("GC handle used from a thread not attached to il2cpp");
+    }
+}
+
+/// Managed objects are represented by pointers to them
+///
+/// # Safety
+/// `object` must point to a live object
+unsafe fn as_raw<'a, T>(object: *const T) -> &'a raw::Il2CppObject {
+    &*object.cast()
+}
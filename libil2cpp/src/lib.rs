@@ -74,9 +74,48 @@
 
 //! Wrappers and raw bindings for Unity's libil2cpp
 
-#[cfg(not(any(feature = "unity2019", feature = "unity2018")))]
+#[cfg(not(any(
+    feature = "unity2022",
+    feature = "unity2021",
+    feature = "unity2020",
+    feature = "unity2019",
+    feature = "unity2018",
+    feature = "dynamic"
+)))]
 compile_error!("No Unity version selected");
 
+#[cfg(any(
+    all(
+        feature = "unity2022",
+        any(
+            feature = "unity2021",
+            feature = "unity2020",
+            feature = "unity2019",
+            feature = "unity2018"
+        )
+    ),
+    all(
+        feature = "unity2021",
+        any(feature = "unity2020", feature = "unity2019", feature = "unity2018")
+    ),
+    all(
+        feature = "unity2020",
+        any(feature = "unity2019", feature = "unity2018")
+    ),
+    all(feature = "unity2019", feature = "unity2018"),
+    all(
+        feature = "dynamic",
+        any(
+            feature = "unity2022",
+            feature = "unity2021",
+            feature = "unity2020",
+            feature = "unity2019",
+            feature = "unity2018"
+        )
+    )
+))]
+compile_error!("Multiple Unity versions selected");
+
 #[cfg(feature = "trace")]
 #[macro_use]
 extern crate tracing;
@@ -91,27 +130,52 @@ macro_rules! debug {
 pub use quest_hook_proc_macros::identity as instrument;
 
 mod array;
+mod by_ref;
+#[cfg(feature = "cache")]
+pub mod cache;
 mod class;
+mod delegate;
+mod dictionary;
+mod error;
+mod event_info;
 mod exception;
 mod field_info;
+mod gc_handle;
+mod list;
+mod md_array;
 mod method_info;
+mod nullable;
 mod object;
 mod parameter_info;
+mod property_info;
 pub mod raw;
+pub mod runtime;
 mod string;
 mod ty;
 mod typecheck;
 
 #[doc(inline)]
-pub use quest_hook_proc_macros::{unsafe_impl_reference_type, unsafe_impl_value_type};
+pub use quest_hook_proc_macros::{
+    unsafe_impl_enum_type, unsafe_impl_reference_type, unsafe_impl_value_type,
+};
 
 pub use array::Il2CppArray;
+pub use by_ref::{Out, Ref};
 pub use class::{FindMethodError, Il2CppClass};
+pub use delegate::{drop_collected_callbacks, Callback, Il2CppDelegate};
+pub use dictionary::{DictionaryIter, Il2CppDictionary};
+pub use error::Il2CppError;
+pub use event_info::EventInfo;
 pub use exception::Il2CppException;
-pub use field_info::FieldInfo;
+pub use field_info::{Accessibility, FieldInfo};
+pub use gc_handle::{GcHandle, WeakGcHandle};
+pub use list::Il2CppList;
+pub use md_array::Il2CppMdArray;
 pub use method_info::{Il2CppReflectionMethod, MethodInfo};
+pub use nullable::Il2CppNullable;
 pub use object::{Il2CppObject, ObjectExt};
 pub use parameter_info::ParameterInfo;
+pub use property_info::PropertyInfo;
 pub use raw::{unbox, WrapRaw};
 pub use string::Il2CppString;
 pub use ty::{Builtin, Il2CppReflectionType, Il2CppType};
@@ -0,0 +1,234 @@
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
+use std::{fmt, ptr, slice};
+
+use crate::{Argument, GcHandle, Il2CppArray, Il2CppException, Il2CppObject, ObjectExt, Type};
+
+/// A C# `System.Collections.Generic.List<T>`
+#[repr(C)]
+pub struct Il2CppList<T: Type> {
+    object: Il2CppObject,
+    items: *mut Il2CppArray<T>,
+    size: i32,
+    version: i32,
+    sync_root: *mut Il2CppObject,
+}
+
+crate::unsafe_impl_reference_type!(in crate for Il2CppList<T> => System.Collections.Generic.List<T>);
+
+impl<T: Type> Il2CppList<T> {
+    /// Creates an empty list, rooted by a [`GcHandle`]
+    pub fn new() -> GcHandle<Self> {
+        <Self as ObjectExt>::new(())
+    }
+
+    /// Creates an empty list, without rooting it
+    ///
+    /// # Safety
+    /// Nothing prevents the list from being collected, so the returned
+    /// reference must not be used after the next garbage collection unless the
+    /// list is rooted by other means
+    pub unsafe fn new_unrooted<'a>() -> &'a mut Self {
+        <Self as ObjectExt>::new_unrooted(())
+    }
+
+    /// Number of values in the list
+    pub fn len(&self) -> usize {
+        self.size as usize
+    }
+
+    /// Whether the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Slice of values in the list
+    pub fn as_slice(&self) -> &[T::Held<'_>] {
+        match unsafe { self.items.as_ref() } {
+            Some(items) => &items.as_slice()[..self.len()],
+            None => &[],
+        }
+    }
+
+    /// Mutable slice of values in the list
+    pub fn as_mut_slice(&mut self) -> &mut [T::Held<'_>] {
+        let len = self.len();
+        match unsafe { self.items.as_mut() } {
+            Some(items) => &mut items.as_mut_slice()[..len],
+            None => &mut [],
+        }
+    }
+
+    /// Iterator over the values in the list
+    pub fn iter(&self) -> slice::Iter<'_, T::Held<'_>> {
+        self.as_slice().iter()
+    }
+
+    /// Appends a value to the end of the list, or returns the exception thrown
+    /// by the list
+    pub fn push<'a>(&mut self, value: T::Held<'a>) -> Result<(), &mut Il2CppException>
+    where
+        T::Held<'a>: Argument,
+    {
+        self.invoke_void("Add", value)
+    }
+
+    /// Inserts a value at position `index` in the list, shifting all values
+    /// after it to the right, or returns the exception thrown by the list
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `index > len`
+    pub fn insert<'a>(
+        &mut self,
+        index: usize,
+        value: T::Held<'a>,
+    ) -> Result<(), &mut Il2CppException>
+    where
+        T::Held<'a>: Argument,
+    {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            len
+        );
+        self.invoke_void("Insert", (index as i32, value))
+    }
+
+    /// Removes and returns the value at position `index` in the list,
+    /// shifting all values after it to the left, or returns the exception
+    /// thrown by the list
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `index >= len`
+    pub fn remove(&mut self, index: usize) -> Result<T::Held<'_>, &mut Il2CppException> {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {}) should be < len (is {})",
+            index,
+            len
+        );
+        // The list no longer holds the value once it is removed, so it can be
+        // moved out, but it is still owned by the list if the removal fails
+        let value = unsafe { ptr::read(self.as_slice()[index..].as_ptr().cast()) };
+        let value = ManuallyDrop::new(value);
+        self.invoke_void("RemoveAt", index as i32)?;
+        Ok(ManuallyDrop::into_inner(value))
+    }
+
+    /// Removes all values from the list, or returns the exception thrown by
+    /// the list
+    pub fn clear(&mut self) -> Result<(), &mut Il2CppException> {
+        self.invoke_void("Clear", ())
+    }
+}
+
+impl<'a, T: Type> IntoIterator for &'a Il2CppList<T> {
+    type Item = &'a T::Held<'a>;
+    type IntoIter = slice::Iter<'a, T::Held<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Type> FromIterator<T::Held<'a>> for GcHandle<Il2CppList<T>>
+where
+    T::Held<'a>: Argument,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T::Held<'a>>,
+    {
+        let mut list = Il2CppList::new();
+        for value in iter {
+            list.push(value).unwrap();
+        }
+        list
+    }
+}
+
+impl<T: Type> fmt::Debug for Il2CppList<T>
+where
+    for<'a> T::Held<'a>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Il2CppList").field(&self.as_slice()).finish()
+    }
+}
+
+impl<T: Type> Deref for Il2CppList<T> {
+    type Target = Il2CppObject;
+
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<T: Type> DerefMut for Il2CppList<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::{fmt, Argument, GcHandle, Il2CppList, Type};
+
+    use std::marker::PhantomData;
+
+    use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+    use serde::ser::{Serialize, Serializer};
+
+    struct ListVisitor<T: Type>(PhantomData<Il2CppList<T>>);
+
+    impl<'de, T: Type> Visitor<'de> for ListVisitor<T>
+    where
+        T::Held<'de>: Deserialize<'de> + Argument,
+    {
+        type Value = GcHandle<Il2CppList<T>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a list of C# compatible values")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut list = Il2CppList::new();
+            while let Some(elem) = seq.next_element()? {
+                list.push(elem).map_err(Error::custom)?;
+            }
+            Ok(list)
+        }
+    }
+
+    impl<'de, T: Type> Deserialize<'de> for GcHandle<Il2CppList<T>>
+    where
+        T::Held<'de>: Deserialize<'de> + Argument,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_seq(ListVisitor(PhantomData))
+        }
+    }
+
+    impl<T: Type> Serialize for Il2CppList<T>
+    where
+        for<'a> T::Held<'a>: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            <[T::Held<'_>] as Serialize>::serialize(self.as_slice(), serializer)
+        }
+    }
+}
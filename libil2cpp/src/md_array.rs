@@ -0,0 +1,234 @@
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::{fmt, slice};
+
+use crate::{raw, GcHandle, Il2CppClass, Il2CppError, Il2CppObject, Il2CppType, Type, WrapRaw};
+
+/// An il2cpp multi-dimensional array of rank `RANK`, such as `int[,]` for
+/// `Il2CppMdArray<i32, 2>`
+///
+/// Values are stored in row-major order and indexed with one index per
+/// dimension, which can be given as an array or a tuple, for example
+/// `arr.get((1, 2))`. Jagged arrays such as `int[][]` are plain
+/// [`Il2CppArray`](crate::Il2CppArray)s of arrays.
+///
+/// An array of rank 1 is an `int[*]`, which can have a lower bound other than
+/// 0, unlike the `int[]` represented by [`Il2CppArray`](crate::Il2CppArray).
+/// Using a rank of 0 fails to compile.
+#[repr(transparent)]
+pub struct Il2CppMdArray<T: Type, const RANK: usize>(raw::Il2CppArray, PhantomData<[T]>);
+
+impl<T: Type, const RANK: usize> Il2CppMdArray<T, RANK> {
+    /// Compile-time check of `RANK`, evaluated when the class is resolved
+    const VALID_RANK: () = assert!(RANK >= 1, "arrays must have a rank of at least 1");
+
+    /// Creates an array with the given dimensions filled with default values,
+    /// rooted by a [`GcHandle`]
+    pub fn new(lengths: [usize; RANK]) -> GcHandle<Self> {
+        unsafe { GcHandle::new(Self::new_unrooted(lengths)) }
+    }
+
+    /// Creates an array with the given dimensions filled with default values,
+    /// without rooting it
+    ///
+    /// # Safety
+    /// Nothing prevents the array from being collected, so the returned
+    /// reference must not be used after the next garbage collection unless the
+    /// array is rooted by other means
+    pub unsafe fn new_unrooted<'a>(lengths: [usize; RANK]) -> &'a mut Self {
+        Self::with_lower_bounds_unrooted(lengths, [0; RANK])
+    }
+
+    /// Creates an array with the given dimensions and lower bounds filled with
+    /// default values, rooted by a [`GcHandle`]
+    pub fn with_lower_bounds(lengths: [usize; RANK], lower_bounds: [i32; RANK]) -> GcHandle<Self> {
+        unsafe { GcHandle::new(Self::with_lower_bounds_unrooted(lengths, lower_bounds)) }
+    }
+
+    /// Creates an array with the given dimensions and lower bounds filled with
+    /// default values, without rooting it
+    ///
+    /// # Safety
+    /// Nothing prevents the array from being collected, so the returned
+    /// reference must not be used after the next garbage collection unless the
+    /// array is rooted by other means
+    pub unsafe fn with_lower_bounds_unrooted<'a>(
+        mut lengths: [usize; RANK],
+        lower_bounds: [i32; RANK],
+    ) -> &'a mut Self {
+        // il2cpp takes lower bounds as sizes but reads them as signed integers
+        let mut lower_bounds = lower_bounds.map(|b| b as isize as usize);
+        let arr = raw::array_new_full(
+            Self::class().raw(),
+            lengths.as_mut_ptr(),
+            lower_bounds.as_mut_ptr(),
+        )
+        .unwrap();
+        Self::wrap_mut(arr)
+    }
+
+    /// Returns a reference to the value at the given index, or `None` if it
+    /// is out of bounds
+    pub fn get<I>(&self, index: I) -> Option<&T::Held<'_>>
+    where
+        I: Into<[i32; RANK]>,
+    {
+        let offset = self.offset(index.into())?;
+        Some(&self.as_slice()[offset])
+    }
+
+    /// Returns a mutable reference to the value at the given index, or `None`
+    /// if it is out of bounds
+    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut T::Held<'_>>
+    where
+        I: Into<[i32; RANK]>,
+    {
+        let offset = self.offset(index.into())?;
+        Some(&mut self.as_mut_slice()[offset])
+    }
+
+    /// Slice of all values in the array, in row-major order
+    pub fn as_slice(&self) -> &[T::Held<'_>] {
+        let ptr = ((self as *const _ as isize) + (raw::kIl2CppSizeOfArray as isize))
+            as *const T::Held<'_>;
+        let len = self.len();
+        unsafe { slice::from_raw_parts(ptr, len) }
+    }
+
+    /// Mutable slice of all values in the array, in row-major order
+    pub fn as_mut_slice(&mut self) -> &mut [T::Held<'_>] {
+        let ptr =
+            ((self as *mut _ as isize) + (raw::kIl2CppSizeOfArray as isize)) as *mut T::Held<'_>;
+        let len = self.len();
+        unsafe { slice::from_raw_parts_mut(ptr, len) }
+    }
+
+    /// Total number of values in the array
+    pub fn len(&self) -> usize {
+        self.raw().max_length
+    }
+
+    /// Whether the array is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Lengths of all dimensions of the array
+    pub fn lengths(&self) -> [usize; RANK] {
+        std::array::from_fn(|dimension| self.length(dimension))
+    }
+
+    /// Length of the given dimension of the array
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `dimension >= RANK`
+    pub fn length(&self, dimension: usize) -> usize {
+        self.bounds(dimension).length
+    }
+
+    /// Lowest valid index in the given dimension of the array
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `dimension >= RANK`
+    pub fn lower_bound(&self, dimension: usize) -> i32 {
+        self.bounds(dimension).lower_bound
+    }
+
+    fn bounds(&self, dimension: usize) -> raw::Il2CppArrayBounds {
+        assert!(
+            dimension < RANK,
+            "dimension (is {}) should be < rank (is {})",
+            dimension,
+            RANK
+        );
+        let bounds = self.raw().bounds;
+        if bounds.is_null() {
+            // Arrays of rank 1 with a lower bound of 0 are vectors, which don't
+            // store their bounds
+            raw::Il2CppArrayBounds {
+                length: self.raw().max_length,
+                lower_bound: 0,
+            }
+        } else {
+            unsafe { *bounds.add(dimension) }
+        }
+    }
+
+    /// Offset of the value at the given index in the array, in row-major order
+    fn offset(&self, index: [i32; RANK]) -> Option<usize> {
+        let mut offset = 0;
+        for (dimension, i) in index.into_iter().enumerate() {
+            let bounds = self.bounds(dimension);
+            let i = usize::try_from(i as i64 - bounds.lower_bound as i64).ok()?;
+            if i >= bounds.length {
+                return None;
+            }
+            offset = offset * bounds.length + i;
+        }
+        Some(offset)
+    }
+}
+
+unsafe impl<T: Type, const RANK: usize> WrapRaw for Il2CppMdArray<T, RANK> {
+    type Raw = raw::Il2CppArray;
+}
+
+unsafe impl<T: Type, const RANK: usize> Type for Il2CppMdArray<T, RANK> {
+    type Held<'a> = Option<&'a mut Self>;
+    type HeldRaw = *mut Self;
+
+    const NAMESPACE: &'static str = "System";
+    const CLASS_NAME: &'static str = "Array";
+
+    fn try_class() -> Result<&'static Il2CppClass, Il2CppError<'static>> {
+        let () = Self::VALID_RANK;
+        // Arrays of rank 1 are bounded so they aren't vectors
+        let class =
+            unsafe { raw::bounded_array_class_get(T::try_class()?.raw(), RANK as u32, RANK == 1) };
+        Ok(unsafe { Il2CppClass::wrap(class) })
+    }
+
+    fn matches_reference_argument(ty: &Il2CppType) -> bool {
+        ty.class().is_assignable_from(Self::class())
+    }
+
+    fn matches_value_argument(_: &Il2CppType) -> bool {
+        false
+    }
+
+    fn matches_reference_parameter(ty: &Il2CppType) -> bool {
+        Self::class().is_assignable_from(ty.class())
+    }
+
+    fn matches_value_parameter(_: &Il2CppType) -> bool {
+        false
+    }
+}
+
+impl<T: Type, const RANK: usize> fmt::Debug for Il2CppMdArray<T, RANK>
+where
+    for<'a> T::Held<'a>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Il2CppMdArray")
+            .field("lengths", &self.lengths())
+            .field("values", &self.as_slice())
+            .finish()
+    }
+}
+
+impl<T: Type, const RANK: usize> Deref for Il2CppMdArray<T, RANK> {
+    type Target = Il2CppObject;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { Il2CppObject::wrap(&self.raw().obj) }
+    }
+}
+
+impl<T: Type, const RANK: usize> DerefMut for Il2CppMdArray<T, RANK> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { Il2CppObject::wrap_mut(&mut self.raw_mut().obj) }
+    }
+}
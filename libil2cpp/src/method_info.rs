@@ -4,16 +4,47 @@ use std::mem::transmute;
 use std::ops::{Deref, DerefMut};
 use std::{fmt, slice};
 
+#[cfg(feature = "cache")]
+use crate::cache;
 use crate::raw::{METHOD_ATTRIBUTE_ABSTRACT, METHOD_ATTRIBUTE_STATIC, METHOD_ATTRIBUTE_VIRTUAL};
+use crate::typecheck::generic::type_array_of;
 use crate::{
-    raw, Arguments, Il2CppClass, Il2CppException, Il2CppObject, Il2CppType, ParameterInfo,
-    Returned, ThisArgument, WrapRaw,
+    raw, Arguments, Generics, Il2CppClass, Il2CppError, Il2CppException, Il2CppObject, Il2CppType,
+    ParameterInfo, Returned, ThisArgument, WrapRaw,
 };
 
-#[cfg(feature = "unity2019")]
+#[cfg(feature = "dynamic")]
+use crate::runtime::{unity_version, UnityVersion};
+
+#[cfg(any(feature = "unity2019", feature = "unity2020"))]
 type ParameterInfoSlice<'a> = &'a [ParameterInfo];
 #[cfg(feature = "unity2018")]
 type ParameterInfoSlice<'a> = &'a [&'static ParameterInfo];
+#[cfg(any(feature = "unity2021", feature = "unity2022", feature = "dynamic"))]
+type ParameterInfoSlice<'a> = Vec<ParameterInfo>;
+
+/// Reads a field of the raw method which is laid out differently depending on
+/// the Unity version, which is everything after `methodPointer`
+macro_rules! versioned {
+    ($method:expr, $($field:tt)+) => {{
+        #[cfg(not(feature = "dynamic"))]
+        {
+            $method.raw().$($field)+
+        }
+
+        #[cfg(feature = "dynamic")]
+        {
+            match unity_version() {
+                UnityVersion::Unity2018 | UnityVersion::Unity2019 | UnityVersion::Unity2020 => {
+                    $method.raw().$($field)+
+                }
+                UnityVersion::Unity2021 | UnityVersion::Unity2022 => {
+                    $method.raw_2021().$($field)+
+                }
+            }
+        }
+    }};
+}
 
 /// Information about a C# method
 #[repr(transparent)]
@@ -25,6 +56,10 @@ unsafe impl Sync for MethodInfo {}
 impl MethodInfo {
     /// Invoke this method, type checking against its signature with the
     /// provided instance, arguments and return type
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the provided types don't match the signature
     pub fn invoke<T, A, R, const N: usize>(
         &self,
         this: T,
@@ -35,11 +70,37 @@ impl MethodInfo {
         A: Arguments<N>,
         R: Returned,
     {
-        assert!(T::matches(self));
-        assert!(A::matches(self));
-        assert!(R::matches(self.return_ty()));
+        self.try_invoke(this, args)
+            .map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Invoke this method, type checking against its signature with the
+    /// provided instance, arguments and return type, returning an error if
+    /// they don't match
+    pub fn try_invoke<T, A, R, const N: usize>(
+        &self,
+        this: T,
+        args: A,
+    ) -> Result<R, Il2CppError<'_>>
+    where
+        T: ThisArgument,
+        A: Arguments<N>,
+        R: Returned,
+    {
+        T::resolve()?;
+        A::resolve()?;
+        R::resolve()?;
+        if !T::matches(self) {
+            return Err(Il2CppError::mismatch(self.class(), T::type_name()));
+        }
+        if !A::matches(self) {
+            return Err(Il2CppError::mismatch(self, A::type_name()));
+        }
+        if !R::matches(self.return_ty()) {
+            return Err(Il2CppError::mismatch(self.return_ty(), R::type_name()));
+        }
 
-        unsafe { self.invoke_unchecked(this, args) }
+        Ok(unsafe { self.invoke_unchecked(this, args) }?)
     }
 
     /// Invoke this method with the given instance and arguments and converting
@@ -57,7 +118,8 @@ impl MethodInfo {
         A: Arguments<N>,
         R: Returned,
     {
-        match self.invoke_raw(this.invokable(), args.invokable().as_mut()) {
+        let r = args.with_invokable(|mut args| self.invoke_raw(this.invokable(), &mut args));
+        match r {
             Ok(r) => Ok(R::from_object(transmute(r))),
             Err(e) => Err(Il2CppException::wrap_mut(e)),
         }
@@ -89,50 +151,233 @@ impl MethodInfo {
 
     /// Name of the method
     pub fn name(&self) -> Cow<'_, str> {
-        let name = self.raw().name;
+        let name = versioned!(self, name);
         assert!(!name.is_null());
         unsafe { CStr::from_ptr(name) }.to_string_lossy()
     }
 
     /// Class the method is from
     pub fn class(&self) -> &Il2CppClass {
-        unsafe { Il2CppClass::wrap_ptr(self.raw().klass) }.unwrap()
+        unsafe { Il2CppClass::wrap_ptr(versioned!(self, klass.cast::<raw::Il2CppClass>())) }
+            .unwrap()
     }
 
     /// Return type of the method
     pub fn return_ty(&self) -> &Il2CppType {
-        unsafe { Il2CppType::wrap_ptr(self.raw().return_type).unwrap() }
+        unsafe { Il2CppType::wrap_ptr(versioned!(self, return_type.cast::<raw::Il2CppType>())) }
+            .unwrap()
     }
 
     /// Parameters the method takes
     pub fn parameters(&self) -> ParameterInfoSlice<'_> {
-        let parameters = self.raw().parameters;
-        if !parameters.is_null() {
-            unsafe { slice::from_raw_parts(parameters.cast(), self.raw().parameters_count as _) }
-        } else {
-            &[]
+        let count = versioned!(self, parameters_count) as usize;
+
+        #[cfg(any(feature = "unity2019", feature = "unity2018", feature = "unity2020"))]
+        {
+            let parameters = self.raw().parameters;
+            if parameters.is_null() {
+                return Default::default();
+            }
+            unsafe { slice::from_raw_parts(parameters.cast(), count) }
+        }
+
+        #[cfg(any(feature = "unity2021", feature = "unity2022"))]
+        {
+            self.parameters_from_types(self.raw().parameters, count)
+        }
+
+        #[cfg(feature = "dynamic")]
+        {
+            match unity_version() {
+                UnityVersion::Unity2018 => {
+                    let parameters = self.raw().parameters.cast::<*const raw::ParameterInfo>();
+                    if parameters.is_null() {
+                        return Vec::new();
+                    }
+                    let parameters = unsafe { slice::from_raw_parts(parameters, count) };
+                    parameters
+                        .iter()
+                        .map(|&p| ParameterInfo::new(unsafe { *p }))
+                        .collect()
+                }
+                UnityVersion::Unity2019 | UnityVersion::Unity2020 => {
+                    let parameters = self.raw().parameters;
+                    if parameters.is_null() {
+                        return Vec::new();
+                    }
+                    let parameters = unsafe { slice::from_raw_parts(parameters, count) };
+                    parameters.iter().copied().map(ParameterInfo::new).collect()
+                }
+                UnityVersion::Unity2021 | UnityVersion::Unity2022 => {
+                    self.parameters_from_types(self.raw_2021().parameters.cast(), count)
+                }
+            }
         }
     }
 
+    /// Assembles the parameters of the method from their types, which are
+    /// all Unity 2021 and later keep
+    #[cfg(any(feature = "unity2021", feature = "unity2022", feature = "dynamic"))]
+    fn parameters_from_types(
+        &self,
+        types: *mut *const raw::Il2CppType,
+        count: usize,
+    ) -> Vec<ParameterInfo> {
+        if types.is_null() {
+            return Vec::new();
+        }
+        let types = unsafe { slice::from_raw_parts(types, count) };
+        types
+            .iter()
+            .enumerate()
+            .map(|(i, &parameter_type)| {
+                let name = unsafe { raw::method_get_param_name(self.raw(), i as u32) }
+                    .unwrap_or_else(|| c"".as_ptr());
+                ParameterInfo::new(raw::ParameterInfo {
+                    name,
+                    position: i as i32,
+                    token: 0,
+                    parameter_type,
+                })
+            })
+            .collect()
+    }
+
+    /// Whether the method's parameters and, if provided, return type have the
+    /// given type names, as defined by [`Il2CppType::is_named`]
+    pub fn has_signature(&self, parameters: &[&str], return_ty: Option<&str>) -> bool {
+        let params = self.parameters();
+        params.len() == parameters.len()
+            && params
+                .iter()
+                .zip(parameters)
+                .all(|(p, n)| p.ty().is_named(n))
+            && return_ty.is_none_or(|n| self.return_ty().is_named(n))
+    }
+
     /// Whether the method is static
     pub fn is_static(&self) -> bool {
-        self.raw().flags as u32 & METHOD_ATTRIBUTE_STATIC != 0
+        versioned!(self, flags) as u32 & METHOD_ATTRIBUTE_STATIC != 0
     }
 
     /// Whether the method is abstract
     pub fn is_abstract(&self) -> bool {
-        self.raw().flags as u32 & METHOD_ATTRIBUTE_ABSTRACT != 0
+        versioned!(self, flags) as u32 & METHOD_ATTRIBUTE_ABSTRACT != 0
     }
 
     /// Whether the method is virtual
     pub fn is_virtual(&self) -> bool {
-        self.raw().flags as u32 & METHOD_ATTRIBUTE_VIRTUAL != 0
+        versioned!(self, flags) as u32 & METHOD_ATTRIBUTE_VIRTUAL != 0
     }
 
     /// Whether the method is generic
     pub fn is_generic(&self) -> bool {
         unsafe { raw::method_is_generic(self.raw()) }
     }
+
+    /// Number of generic parameters of the method, or 0 if it isn't generic
+    pub fn generic_parameter_count(&self) -> usize {
+        if !self.is_generic() {
+            return 0;
+        }
+        let mut method = self;
+        // Inflated methods, such as the ones of generic class instances, point
+        // to their definition instead of having a generic container
+        if unsafe { raw::method_is_inflated(method.raw()) } {
+            method = unsafe {
+                let generic = versioned!(
+                    method,
+                    __bindgen_anon_2
+                        .genericMethod
+                        .cast::<raw::Il2CppGenericMethod>()
+                );
+                Self::wrap_ptr((*generic).methodDefinition).unwrap()
+            };
+        }
+        let container = unsafe { method.generic_container().as_ref() };
+        container.map_or(0, |c| c.type_argc as usize)
+    }
+
+    /// Generic container of the method, which Unity 2020 and later refer to
+    /// with a metadata handle pointing to the same structure
+    fn generic_container(&self) -> *const raw::Il2CppGenericContainer {
+        #[cfg(any(feature = "unity2019", feature = "unity2018"))]
+        unsafe {
+            self.raw().__bindgen_anon_2.genericContainer
+        }
+
+        #[cfg(any(feature = "unity2020", feature = "unity2021", feature = "unity2022"))]
+        unsafe {
+            self.raw().__bindgen_anon_2.genericContainerHandle.cast()
+        }
+
+        #[cfg(feature = "dynamic")]
+        match unity_version() {
+            UnityVersion::Unity2018 | UnityVersion::Unity2019 | UnityVersion::Unity2020 => unsafe {
+                self.raw().__bindgen_anon_2.genericContainer
+            },
+            UnityVersion::Unity2021 | UnityVersion::Unity2022 => unsafe {
+                self.raw_2021()
+                    .__bindgen_anon_2
+                    .genericContainerHandle
+                    .cast()
+            },
+        }
+    }
+
+    #[cfg(feature = "dynamic")]
+    fn raw_2021(&self) -> &raw::unity2021::MethodInfo {
+        unsafe { &*(self as *const Self).cast() }
+    }
+
+    /// Instanciates a generic method template with the provided generic
+    /// arguments
+    pub fn make_generic<G>(&self) -> Result<Option<&'static Self>, &mut Il2CppException>
+    where
+        G: Generics + 'static,
+    {
+        #[cfg(feature = "cache")]
+        let key = {
+            let key = cache::GenericKey {
+                template: self as *const Self as usize,
+                generics: std::any::TypeId::of::<G>(),
+            };
+            if let Some(method) = cache::GENERIC_METHODS.get(&key) {
+                debug!("cache hit");
+                return Ok(Some(method));
+            }
+            debug!("cache miss");
+            key
+        };
+
+        match self.reflection_object().make_generic::<G>() {
+            Ok(Some(method)) => {
+                let method = unsafe { Self::wrap(raw::method_get_from_reflection(method.raw())) };
+
+                #[cfg(feature = "cache")]
+                cache::GENERIC_METHODS.insert(key, method);
+
+                Ok(Some(method))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Instanciates a generic method template with generic arguments which are
+    /// only known at runtime, returning `None` if it can't be instantiated
+    /// with them
+    pub(crate) fn make_generic_with(&self, generics: &[&Il2CppClass]) -> Option<&'static Self> {
+        if !self.is_generic() {
+            return None;
+        }
+        let generics = type_array_of(generics);
+        let method = self
+            .reflection_object()
+            .make_generic_from(generics)
+            .ok()??;
+        Some(unsafe { Self::wrap(raw::method_get_from_reflection(method.raw())) })
+    }
 }
 
 unsafe impl WrapRaw for MethodInfo {
@@ -188,6 +433,40 @@ impl Il2CppReflectionMethod {
     pub fn method_info(&self) -> &MethodInfo {
         unsafe { MethodInfo::wrap(raw::method_get_from_reflection(self.raw())) }
     }
+
+    /// Instanciates a generic method template with the provided generic
+    /// arguments
+    pub fn make_generic<G>(&self) -> Result<Option<&Self>, &mut Il2CppException>
+    where
+        G: Generics,
+    {
+        self.make_generic_from(G::type_array())
+    }
+
+    /// Instanciates a generic method template with an array of
+    /// `System.RuntimeType`s as generic arguments
+    pub(crate) fn make_generic_from(
+        &self,
+        generics: &mut raw::Il2CppArray,
+    ) -> Result<Option<&Self>, &'static mut Il2CppException> {
+        let make_generic = self
+            .class()
+            .find_method_unchecked("MakeGenericMethod", 1)
+            .unwrap();
+        let ret = unsafe {
+            make_generic.invoke_raw(
+                self as *const Self as *mut c_void,
+                [(generics as *mut raw::Il2CppArray).cast()].as_mut(),
+            )
+        };
+        let obj = match ret {
+            Ok(Some(obj)) => obj,
+            Ok(None) => return Ok(None),
+            Err(e) => return Err(unsafe { Il2CppException::wrap_mut(e) }),
+        };
+        let method = unsafe { &mut *(obj as *mut raw::Il2CppObject).cast() };
+        Ok(Some(method))
+    }
 }
 
 impl Deref for Il2CppReflectionMethod {
@@ -0,0 +1,238 @@
+use std::ffi::c_void;
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use crate::{
+    raw, Argument, Il2CppClass, Il2CppError, Il2CppObject, Il2CppType, Parameter, Return, Returned,
+    Type, WrapRaw,
+};
+
+/// A C# `System.Nullable<T>`, as stored in fields and passed by value
+///
+/// Converts to and from [`Option<T>`]. Builtin value types such as `i32` can
+/// use `Option<T>` directly as an argument, parameter or return type, but value
+/// types defined in other crates have to use `Il2CppNullable<T>` because of the
+/// orphan rule. Values held in fields and arrays are always
+/// `Il2CppNullable<T>`, since that is how nullable values are laid out in
+/// memory, so loading a field of type `Option<i32>` returns an
+/// `Il2CppNullable<i32>`.
+#[repr(C)]
+pub struct Il2CppNullable<T> {
+    value: MaybeUninit<T>,
+    has_value: bool,
+}
+
+impl<T> Il2CppNullable<T> {
+    /// Returns a reference to the value, if there is one
+    pub fn as_ref(&self) -> Option<&T> {
+        self.has_value
+            .then(|| unsafe { self.value.assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the value, if there is one
+    pub fn as_mut(&mut self) -> Option<&mut T> {
+        self.has_value
+            .then(|| unsafe { self.value.assume_init_mut() })
+    }
+}
+
+impl<T> From<Option<T>> for Il2CppNullable<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => Self {
+                value: MaybeUninit::new(value),
+                has_value: true,
+            },
+            None => Self {
+                value: MaybeUninit::zeroed(),
+                has_value: false,
+            },
+        }
+    }
+}
+
+impl<T> From<Il2CppNullable<T>> for Option<T> {
+    fn from(nullable: Il2CppNullable<T>) -> Self {
+        nullable
+            .has_value
+            .then(|| unsafe { nullable.value.assume_init() })
+    }
+}
+
+unsafe impl<T: Type> Type for Il2CppNullable<T> {
+    type Held<'a> = Self;
+    type HeldRaw = Self;
+
+    const NAMESPACE: &'static str = "System";
+    const CLASS_NAME: &'static str = "Nullable`1";
+
+    fn try_class() -> Result<&'static Il2CppClass, Il2CppError<'static>> {
+        let not_found = || Il2CppError::ClassNotFound {
+            namespace: Self::NAMESPACE.to_owned(),
+            name: Self::CLASS_NAME.to_owned(),
+        };
+        Il2CppClass::find(Self::NAMESPACE, Self::CLASS_NAME)
+            .ok_or_else(not_found)?
+            .make_generic::<T>()?
+            .ok_or_else(not_found)
+    }
+
+    fn matches_value_argument(ty: &Il2CppType) -> bool {
+        !ty.is_ref() && ty.class().is_assignable_from(Self::class())
+    }
+
+    fn matches_reference_argument(ty: &Il2CppType) -> bool {
+        ty.is_ref() && ty.class().is_assignable_from(Self::class())
+    }
+
+    fn matches_value_parameter(ty: &Il2CppType) -> bool {
+        !ty.is_ref() && Self::class().is_assignable_from(ty.class())
+    }
+
+    fn matches_reference_parameter(ty: &Il2CppType) -> bool {
+        ty.is_ref() && Self::class().is_assignable_from(ty.class())
+    }
+}
+
+unsafe impl<T: Type> Argument for Il2CppNullable<T> {
+    type Type = Self;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        <Self as Type>::try_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        <Self as Type>::matches_value_argument(ty)
+    }
+
+    fn type_name() -> String {
+        <Self as Type>::class().ty().to_string()
+    }
+
+    fn with_invokable<O>(&mut self, f: impl FnOnce(*mut c_void) -> O) -> O {
+        f((self as *mut Self).cast())
+    }
+}
+
+unsafe impl<T: Type> Parameter for Il2CppNullable<T> {
+    type Actual = Self;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        <Self as Type>::try_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        <Self as Type>::matches_value_parameter(ty)
+    }
+
+    fn type_name() -> String {
+        <Self as Type>::class().ty().to_string()
+    }
+
+    fn from_actual(actual: Self::Actual) -> Self {
+        actual
+    }
+
+    fn into_actual(self) -> Self::Actual {
+        self
+    }
+}
+
+unsafe impl<T: Type> Returned for Il2CppNullable<T> {
+    type Type = Self;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        <Self as Type>::try_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        <Self as Type>::matches_returned(ty)
+    }
+
+    fn type_name() -> String {
+        <Self as Type>::class().ty().to_string()
+    }
+
+    /// Boxing a nullable value results in either `null` or a boxed `T`
+    fn from_object(object: Option<&mut Il2CppObject>) -> Self {
+        object.map(|o| unsafe { raw::unbox(o.raw()) }).into()
+    }
+}
+
+unsafe impl<T: Type> Return for Il2CppNullable<T> {
+    type Actual = Self;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        <Self as Type>::try_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        <Self as Type>::matches_return(ty)
+    }
+
+    fn type_name() -> String {
+        <Self as Type>::class().ty().to_string()
+    }
+
+    fn into_actual(self) -> Self::Actual {
+        self
+    }
+
+    fn from_actual(actual: Self::Actual) -> Self {
+        actual
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Il2CppNullable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Il2CppNullable")
+            .field(&self.as_ref())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+    use std::ptr;
+
+    use super::Il2CppNullable;
+    use crate::{Argument, Arguments, Type};
+
+    /// Copies the value an argument is staged as, like il2cpp does when
+    /// storing it into a field or passing it by value
+    fn staged<A: Argument, T>(mut arg: A) -> T {
+        arg.with_invokable(|ptr| unsafe { ptr::read(ptr.cast()) })
+    }
+
+    #[test]
+    fn option_argument() {
+        let some: Il2CppNullable<i32> = staged(Some(5));
+        assert_eq!(some.as_ref(), Some(&5));
+        let none: Il2CppNullable<bool> = staged(None::<bool>);
+        assert_eq!(none.as_ref(), None);
+
+        let mut args = (Some(1.5_f64), 2_u8, None::<i64>);
+        args.with_invokable(|[a, b, c]| unsafe {
+            let a = ptr::read(a.cast::<Il2CppNullable<f64>>());
+            let c = ptr::read(c.cast::<Il2CppNullable<i64>>());
+            assert_eq!(Option::from(a), Some(1.5));
+            assert_eq!(*b.cast::<u8>(), 2);
+            assert_eq!(c.as_ref(), None);
+        });
+    }
+
+    #[test]
+    fn option_field() {
+        // Fields of type `Option<T>` hold `Il2CppNullable<T>`, so a stored
+        // value can be loaded back
+        assert_eq!(
+            size_of::<<Option<bool> as Type>::Held<'static>>(),
+            size_of::<Il2CppNullable<bool>>()
+        );
+        let field: <Option<u16> as Type>::Held<'_> = staged(Some(7_u16));
+        assert_eq!(Option::from(field), Some(7));
+        let field: <Option<u16> as Type>::Held<'_> = staged(None::<u16>);
+        assert_eq!(field.as_ref(), None);
+    }
+}
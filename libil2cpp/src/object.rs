@@ -1,7 +1,10 @@
 use std::fmt;
 use std::ops::DerefMut;
 
-use crate::{raw, Argument, Arguments, Il2CppClass, Il2CppException, Returned, Type, WrapRaw};
+use crate::{
+    raw, Argument, Arguments, GcHandle, Generics, Il2CppClass, Il2CppError, Il2CppException,
+    Returned, Type, WrapRaw,
+};
 
 /// An il2cpp object
 #[repr(transparent)]
@@ -28,8 +31,8 @@ impl Il2CppObject {
         A: Arguments<N>,
         R: Returned,
     {
-        let method = self.class().find_method::<A, R, N>(name).unwrap();
-        unsafe { method.invoke_unchecked(self, args) }
+        self.try_invoke(name, args)
+            .map_err(Il2CppError::unwrap_exception)
     }
 
     /// Invokes the `void` method with the given name on `self` using the
@@ -46,8 +49,77 @@ impl Il2CppObject {
     where
         A: Arguments<N>,
     {
-        let method = self.class().find_method::<A, (), N>(name).unwrap();
-        unsafe { method.invoke_unchecked(self, args) }
+        self.try_invoke_void(name, args)
+            .map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Invokes the method with the given name on `self` using the given
+    /// arguments, with type checking, returning an error if a matching method
+    /// can't be found
+    pub fn try_invoke<A, R, const N: usize>(
+        &mut self,
+        name: &str,
+        args: A,
+    ) -> Result<R, Il2CppError<'_>>
+    where
+        A: Arguments<N>,
+        R: Returned,
+    {
+        let method = self.class().try_find_method::<A, R, N>(name)?;
+        Ok(unsafe { method.invoke_unchecked(self, args) }?)
+    }
+
+    /// Invokes the `void` method with the given name on `self` using the
+    /// given arguments, with type checking, returning an error if a matching
+    /// method can't be found
+    pub fn try_invoke_void<A, const N: usize>(
+        &mut self,
+        name: &str,
+        args: A,
+    ) -> Result<(), Il2CppError<'_>>
+    where
+        A: Arguments<N>,
+    {
+        let method = self.class().try_find_method::<A, (), N>(name)?;
+        Ok(unsafe { method.invoke_unchecked(self, args) }?)
+    }
+
+    /// Invokes the generic method with the given name on `self`, instantiated
+    /// with the given generic arguments, using the given arguments, with type
+    /// checking
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if a matching method can't be found.
+    pub fn invoke_generic<G, A, R, const N: usize>(
+        &mut self,
+        name: &str,
+        args: A,
+    ) -> Result<R, &mut Il2CppException>
+    where
+        G: Generics + 'static,
+        A: Arguments<N>,
+        R: Returned,
+    {
+        self.try_invoke_generic::<G, A, R, N>(name, args)
+            .map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Invokes the generic method with the given name on `self`, instantiated
+    /// with the given generic arguments, using the given arguments, with type
+    /// checking, returning an error if a matching method can't be found
+    pub fn try_invoke_generic<G, A, R, const N: usize>(
+        &mut self,
+        name: &str,
+        args: A,
+    ) -> Result<R, Il2CppError<'_>>
+    where
+        G: Generics + 'static,
+        A: Arguments<N>,
+        R: Returned,
+    {
+        let method = self.class().try_find_generic_method::<G, A, R, N>(name)?;
+        Ok(unsafe { method.invoke_unchecked(self, args) }?)
     }
 
     /// Loads a value from a field of `self` with the given name, with type
@@ -55,13 +127,27 @@ impl Il2CppObject {
     ///
     /// # Panics
     ///
-    /// This method will panic if the given field can't be found
+    /// This method will panic if the given field can't be found or if its
+    /// type doesn't match
     pub fn load<T>(&mut self, field: &str) -> T::Held<'_>
     where
         T: Type,
     {
-        let field = self.class().find_field(field).unwrap();
-        field.load::<T>(self)
+        match self.try_load::<T>(field) {
+            Ok(value) => value,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Loads a value from a field of `self` with the given name, with type
+    /// checking, returning an error if the field can't be found or if its
+    /// type doesn't match
+    pub fn try_load<T>(&mut self, field: &str) -> Result<T::Held<'_>, Il2CppError<'static>>
+    where
+        T: Type,
+    {
+        let field = self.class().try_find_field(field)?;
+        field.try_load::<T>(self)
     }
 
     /// Stores a given value into a field of `self` with the given name, with
@@ -69,13 +155,78 @@ impl Il2CppObject {
     ///
     /// # Panics
     ///
-    /// This method will panic if the given field can't be found
+    /// This method will panic if the given field can't be found or if its
+    /// type doesn't match
     pub fn store<A>(&mut self, field: &str, value: A)
     where
         A: Argument,
     {
-        let field = self.class().find_field(field).unwrap();
-        field.store(self, value);
+        if let Err(e) = self.try_store(field, value) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Stores a given value into a field of `self` with the given name, with
+    /// type checking, returning an error if the field can't be found or if its
+    /// type doesn't match
+    pub fn try_store<A>(&mut self, field: &str, value: A) -> Result<(), Il2CppError<'static>>
+    where
+        A: Argument,
+    {
+        let field = self.class().try_find_field(field)?;
+        field.try_store(self, value)
+    }
+
+    /// Gets the value of a property of `self` with the given name, with type
+    /// checking
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the given property or its getter can't be
+    /// found or if its type doesn't match
+    pub fn get<R>(&mut self, property: &str) -> Result<R, &mut Il2CppException>
+    where
+        R: Returned,
+    {
+        self.try_get(property)
+            .map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Gets the value of a property of `self` with the given name, with type
+    /// checking, returning an error if the property or its getter can't be
+    /// found or if its type doesn't match
+    pub fn try_get<R>(&mut self, property: &str) -> Result<R, Il2CppError<'_>>
+    where
+        R: Returned,
+    {
+        let property = self.class().try_find_property(property)?;
+        property.try_get(self)
+    }
+
+    /// Sets the value of a property of `self` with the given name, with type
+    /// checking
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the given property or its setter can't be
+    /// found or if its type doesn't match
+    pub fn set<A>(&mut self, property: &str, value: A) -> Result<(), &mut Il2CppException>
+    where
+        A: Argument,
+    {
+        self.try_set(property, value)
+            .map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Sets the value of a property of `self` with the given name, with type
+    /// checking, returning an error if the property or its setter can't be
+    /// found or if its type doesn't match
+    pub fn try_set<A>(&mut self, property: &str, value: A) -> Result<(), Il2CppError<'_>>
+    where
+        A: Argument,
+    {
+        let property = self.class().try_find_property(property)?;
+        property.try_set(self, value)
     }
 }
 
@@ -96,12 +247,27 @@ impl fmt::Debug for Il2CppObject {
 pub trait ObjectExt:
     for<'a> Type<Held<'a> = Option<&'a mut Self>> + DerefMut<Target = Il2CppObject> + Sized
 {
-    /// Creates a new object using the constructor taking the given arguments
-    fn new<A, const N: usize>(args: A) -> &'static mut Self
+    /// Creates a new object using the constructor taking the given arguments,
+    /// rooted by a [`GcHandle`]
+    fn new<A, const N: usize>(args: A) -> GcHandle<Self>
+    where
+        A: Arguments<N>,
+    {
+        unsafe { GcHandle::new(Self::new_unrooted(args)) }
+    }
+
+    /// Creates a new object using the constructor taking the given arguments,
+    /// without rooting it
+    ///
+    /// # Safety
+    /// Nothing prevents the object from being collected, so the returned
+    /// reference must not be used after the next garbage collection unless the
+    /// object is rooted by other means
+    unsafe fn new_unrooted<'a, A, const N: usize>(args: A) -> &'a mut Self
     where
         A: Arguments<N>,
     {
-        let object: &mut Self = Self::class().instantiate();
+        let object: &mut Self = Self::class().instantiate_unrooted();
         object.invoke_void(".ctor", args).unwrap();
         object
     }
@@ -12,6 +12,11 @@ unsafe impl Send for ParameterInfo {}
 unsafe impl Sync for ParameterInfo {}
 
 impl ParameterInfo {
+    #[cfg(any(feature = "unity2021", feature = "unity2022", feature = "dynamic"))]
+    pub(crate) fn new(raw: raw::ParameterInfo) -> Self {
+        Self(raw)
+    }
+
     /// Name of the parameter
     pub fn name(&self) -> Cow<'_, str> {
         let name = self.raw().name;
@@ -25,7 +30,7 @@ impl ParameterInfo {
     }
 
     /// Type of the parameter
-    pub fn ty(&self) -> &Il2CppType {
+    pub fn ty(&self) -> &'static Il2CppType {
         unsafe { Il2CppType::wrap_ptr(self.raw().parameter_type) }.unwrap()
     }
 }
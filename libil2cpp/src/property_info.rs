@@ -0,0 +1,125 @@
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::fmt;
+
+use crate::{
+    raw, Argument, Il2CppClass, Il2CppError, Il2CppException, Il2CppType, MethodInfo, Returned,
+    ThisArgument, WrapRaw,
+};
+
+/// Information about a C# property
+#[repr(transparent)]
+pub struct PropertyInfo(raw::PropertyInfo);
+
+unsafe impl Send for PropertyInfo {}
+unsafe impl Sync for PropertyInfo {}
+
+impl PropertyInfo {
+    /// Get the value of the property by invoking its getter, with type
+    /// checking
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the property has no getter or if the
+    /// provided types don't match its signature
+    pub fn get<T, R>(&self, this: T) -> Result<R, &mut Il2CppException>
+    where
+        T: ThisArgument,
+        R: Returned,
+    {
+        self.try_get(this).map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Get the value of the property by invoking its getter, with type
+    /// checking, returning an error if the property has no getter or if the
+    /// provided types don't match its signature
+    pub fn try_get<T, R>(&self, this: T) -> Result<R, Il2CppError<'_>>
+    where
+        T: ThisArgument,
+        R: Returned,
+    {
+        let getter = self.getter().ok_or_else(|| self.missing_accessor("get"))?;
+        getter.try_invoke(this, ())
+    }
+
+    /// Set the value of the property by invoking its setter, with type
+    /// checking
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the property has no setter or if the
+    /// provided types don't match its signature
+    pub fn set<T, A>(&self, this: T, value: A) -> Result<(), &mut Il2CppException>
+    where
+        T: ThisArgument,
+        A: Argument,
+    {
+        self.try_set(this, value)
+            .map_err(Il2CppError::unwrap_exception)
+    }
+
+    /// Set the value of the property by invoking its setter, with type
+    /// checking, returning an error if the property has no setter or if the
+    /// provided types don't match its signature
+    pub fn try_set<T, A>(&self, this: T, value: A) -> Result<(), Il2CppError<'_>>
+    where
+        T: ThisArgument,
+        A: Argument,
+    {
+        let setter = self.setter().ok_or_else(|| self.missing_accessor("set"))?;
+        setter.try_invoke(this, value)
+    }
+
+    /// Name of the property
+    pub fn name(&self) -> Cow<'_, str> {
+        let name = self.raw().name;
+        assert!(!name.is_null());
+        unsafe { CStr::from_ptr(name) }.to_string_lossy()
+    }
+
+    /// Class the property is from
+    pub fn parent(&self) -> &Il2CppClass {
+        unsafe { Il2CppClass::wrap_ptr(self.raw().parent) }.unwrap()
+    }
+
+    /// Getter method of the property, if it has one
+    pub fn getter(&self) -> Option<&'static MethodInfo> {
+        unsafe { MethodInfo::wrap_ptr(self.raw().get) }
+    }
+
+    /// Setter method of the property, if it has one
+    pub fn setter(&self) -> Option<&'static MethodInfo> {
+        unsafe { MethodInfo::wrap_ptr(self.raw().set) }
+    }
+
+    /// Type of the property, taken from its accessors, if it has any
+    pub fn ty(&self) -> Option<&Il2CppType> {
+        match (self.getter(), self.setter()) {
+            (Some(getter), _) => Some(getter.return_ty()),
+            (None, Some(setter)) => setter.parameters().last().map(|p| p.ty()),
+            (None, None) => None,
+        }
+    }
+
+    fn missing_accessor(&self, prefix: &str) -> Il2CppError<'static> {
+        Il2CppError::MethodNotFound {
+            class: self.parent().to_string(),
+            name: format!("{}_{}", prefix, self.name()),
+        }
+    }
+}
+
+unsafe impl WrapRaw for PropertyInfo {
+    type Raw = raw::PropertyInfo;
+}
+
+impl fmt::Debug for PropertyInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PropertyInfo")
+            .field("name", &self.name())
+            .field("type", &self.ty())
+            .field("getter", &self.getter().is_some())
+            .field("setter", &self.setter().is_some())
+            .finish()
+    }
+}
@@ -1,39 +1,498 @@
 #![allow(missing_docs)]
 
-use libloading::{Library, Symbol};
+use libloading::Library;
 use quest_hook_proc_macros::il2cpp_functions;
 use std::ffi::c_void;
-use std::sync::{LazyLock, OnceLock};
 use std::os::raw::c_char;
 
 use super::{
-    FieldInfo, Il2CppArray, Il2CppAssembly, Il2CppClass, Il2CppDomain, Il2CppException,
-    Il2CppImage, Il2CppMethodPointer, Il2CppObject, Il2CppReflectionMethod, Il2CppReflectionType,
-    Il2CppString, Il2CppType, MethodInfo,
+    il2cpp_WorldChangedCallback, il2cpp_register_object_callback, EventInfo, FieldInfo,
+    Il2CppArray, Il2CppAssembly, Il2CppChar, Il2CppClass, Il2CppCustomAttrInfo, Il2CppDomain,
+    Il2CppException, Il2CppFrameWalkFunc, Il2CppImage, Il2CppLogCallback,
+    Il2CppManagedMemorySnapshot, Il2CppMemoryCallbacks, Il2CppMethodPointer, Il2CppObject,
+    Il2CppProfileAllocFunc, Il2CppProfileFileIOFunc, Il2CppProfileFlags, Il2CppProfileFunc,
+    Il2CppProfileGCFunc, Il2CppProfileGCResizeFunc, Il2CppProfileMethodFunc,
+    Il2CppProfileThreadFunc, Il2CppProfiler, Il2CppReflectionMethod, Il2CppReflectionType,
+    Il2CppRuntimeUnhandledExceptionPolicy, Il2CppSetFindPlugInCallback, Il2CppStackFrameInfo,
+    Il2CppStat, Il2CppString, Il2CppThread, Il2CppType, MethodInfo, PropertyInfo,
 };
+use crate::runtime::{functions, LoadError};
 
+// Only the functions the crate itself calls are required, the others being
+// optional so that runtimes lacking some of them, such as stripped builds or
+// other Unity versions, still load
 il2cpp_functions! {
-    pub fn domain_get() -> &'static Il2CppDomain;
-    pub fn domain_get_assemblies(domain: &Il2CppDomain, size: &mut usize) -> &'static [&'static Il2CppAssembly];
+    // runtime
+    #[optional]
+    pub fn init(domain_name: *const c_char) -> i32;
+    #[optional]
+    pub fn init_utf16(domain_name: *const Il2CppChar) -> i32;
+    #[optional]
+    pub fn shutdown();
+    #[optional]
+    pub fn set_config_dir(config_path: *const c_char);
+    #[optional]
+    pub fn set_data_dir(data_path: *const c_char);
+    #[optional]
+    pub fn set_temp_dir(temp_path: *const c_char);
+    #[optional]
+    pub fn set_commandline_arguments(argc: i32, argv: *const *const c_char, basedir: *const c_char);
+    #[optional]
+    pub fn set_commandline_arguments_utf16(argc: i32, argv: *const *const Il2CppChar, basedir: *const c_char);
+    #[optional]
+    pub fn set_config_utf16(executable_path: *const Il2CppChar);
+    #[optional]
+    pub fn set_config(executable_path: *const c_char);
+    #[optional]
+    pub fn set_memory_callbacks(callbacks: &mut Il2CppMemoryCallbacks);
+    #[optional]
+    pub fn get_corlib() -> &'static Il2CppImage;
+    #[optional]
+    pub fn add_internal_call(name: *const c_char, method: Il2CppMethodPointer);
+    #[optional]
+    pub fn resolve_icall(name: *const c_char) -> Il2CppMethodPointer;
+    #[optional]
+    pub fn alloc(size: usize) -> *mut c_void;
+    #[optional]
+    pub fn free(ptr: *mut c_void);
+
+    // array
+    pub fn array_class_get(element_class: &Il2CppClass, rank: u32) -> &'static Il2CppClass;
+    pub fn array_length(array: &Il2CppArray) -> u32;
+    #[optional]
+    pub fn array_get_byte_length(array: &Il2CppArray) -> u32;
+    pub fn array_new(element_class: &Il2CppClass, length: usize) -> Option<&'static mut Il2CppArray>;
+    #[optional]
+    pub fn array_new_specific(array_class: &Il2CppClass, length: usize) -> Option<&'static mut Il2CppArray>;
+    pub fn array_new_full(array_class: &Il2CppClass, lengths: *mut usize, lower_bounds: *mut usize) -> Option<&'static mut Il2CppArray>;
+    pub fn bounded_array_class_get(element_class: &Il2CppClass, rank: u32, bounded: bool) -> &'static Il2CppClass;
+    #[optional]
+    pub fn array_element_size(array_class: &Il2CppClass) -> i32;
+
+    // assembly
     pub fn assembly_get_image(assembly: &Il2CppAssembly) -> Option<&'static Il2CppImage>;
-    pub fn class_from_name(image: &Il2CppImage, namespace: *const c_char, name: *const c_char) -> Option<&'static Il2CppClass>;
+
+    // class
+    #[optional]
+    pub fn class_for_each(callback: Option<unsafe extern "C" fn(class: *mut Il2CppClass, user_data: *mut c_void)>, user_data: *mut c_void);
+    #[optional]
+    pub fn class_enum_basetype(class: &Il2CppClass) -> Option<&'static Il2CppType>;
+    #[optional]
+    pub fn class_is_generic(class: &Il2CppClass) -> bool;
+    #[optional]
+    pub fn class_is_inflated(class: &Il2CppClass) -> bool;
+    pub fn class_is_assignable_from(class: &Il2CppClass, other_class: &Il2CppClass) -> bool;
+    #[optional]
+    pub fn class_is_subclass_of(class: &Il2CppClass, other_class: &Il2CppClass, check_interfaces: bool) -> bool;
+    #[optional]
+    pub fn class_has_parent(class: &Il2CppClass, parent: &Il2CppClass) -> bool;
     pub fn class_from_il2cpp_type(ty: &Il2CppType) -> &'static Il2CppClass;
+    pub fn class_from_name(image: &Il2CppImage, namespace: *const c_char, name: *const c_char) -> Option<&'static Il2CppClass>;
     pub fn class_from_system_type(ty: &Il2CppReflectionType) -> &'static Il2CppClass;
-    pub fn class_is_assignable_from(class: &Il2CppClass, other_class: &Il2CppClass) -> bool;
+    #[optional]
+    pub fn class_get_element_class(class: &Il2CppClass) -> &'static Il2CppClass;
+    #[optional]
+    pub fn class_get_events(class: &Il2CppClass, iter: &mut *mut c_void) -> Option<&'static EventInfo>;
+    #[optional]
+    pub fn class_get_fields(class: &Il2CppClass, iter: &mut *mut c_void) -> Option<&'static FieldInfo>;
+    #[optional]
+    pub fn class_get_nested_types(class: &Il2CppClass, iter: &mut *mut c_void) -> Option<&'static Il2CppClass>;
+    #[optional]
+    pub fn class_get_interfaces(class: &Il2CppClass, iter: &mut *mut c_void) -> Option<&'static Il2CppClass>;
+    #[optional]
+    pub fn class_get_properties(class: &Il2CppClass, iter: &mut *mut c_void) -> Option<&'static PropertyInfo>;
+    #[optional]
+    pub fn class_get_property_from_name(class: &Il2CppClass, name: *const c_char) -> Option<&'static PropertyInfo>;
+    #[optional]
+    pub fn class_get_field_from_name(class: &Il2CppClass, name: *const c_char) -> Option<&'static FieldInfo>;
+    #[optional]
+    pub fn class_get_methods(class: &Il2CppClass, iter: &mut *mut c_void) -> Option<&'static MethodInfo>;
     pub fn class_get_method_from_name(class: &Il2CppClass, name: *const c_char, args_count: u32) -> Option<&'static MethodInfo>;
+    #[optional]
+    pub fn class_get_name(class: &Il2CppClass) -> *const c_char;
+    #[optional]
+    pub fn class_get_namespace(class: &Il2CppClass) -> *const c_char;
+    #[optional]
+    pub fn class_get_parent(class: &Il2CppClass) -> Option<&'static Il2CppClass>;
+    #[optional]
+    pub fn class_get_declaring_type(class: &Il2CppClass) -> Option<&'static Il2CppClass>;
+    #[optional]
+    pub fn class_instance_size(class: &Il2CppClass) -> i32;
+    #[optional]
+    pub fn class_num_fields(class: &Il2CppClass) -> usize;
+    #[optional]
+    pub fn class_is_valuetype(class: &Il2CppClass) -> bool;
+    #[optional]
+    pub fn class_value_size(class: &Il2CppClass, align: Option<&mut u32>) -> i32;
+    #[optional]
+    pub fn class_is_blittable(class: &Il2CppClass) -> bool;
+    #[optional]
+    pub fn class_get_flags(class: &Il2CppClass) -> i32;
+    #[optional]
+    pub fn class_is_abstract(class: &Il2CppClass) -> bool;
+    #[optional]
+    pub fn class_is_interface(class: &Il2CppClass) -> bool;
+    #[optional]
+    pub fn class_array_element_size(class: &Il2CppClass) -> i32;
+    #[optional]
+    pub fn class_from_type(ty: &Il2CppType) -> &'static Il2CppClass;
     pub fn class_get_type(class: &Il2CppClass) -> &'static Il2CppType;
-    pub fn field_set_value(obj: &mut Il2CppObject, field: &FieldInfo, value: *const c_void);
+    #[optional]
+    pub fn class_get_type_token(class: &Il2CppClass) -> u32;
+    #[optional]
+    pub fn class_has_attribute(class: &Il2CppClass, attr_class: &Il2CppClass) -> bool;
+    #[optional]
+    pub fn class_has_references(class: &Il2CppClass) -> bool;
+    #[optional]
+    pub fn class_is_enum(class: &Il2CppClass) -> bool;
+    #[optional]
+    pub fn class_get_image(class: &Il2CppClass) -> &'static Il2CppImage;
+    #[optional]
+    pub fn class_get_assemblyname(class: &Il2CppClass) -> *const c_char;
+    #[optional]
+    pub fn class_get_rank(class: &Il2CppClass) -> i32;
+    #[optional]
+    pub fn class_get_data_size(class: &Il2CppClass) -> u32;
+    #[optional]
+    pub fn class_get_static_field_data(class: &Il2CppClass) -> *mut c_void;
+    #[optional]
+    pub fn class_get_bitmap_size(class: &Il2CppClass) -> usize;
+    #[optional]
+    pub fn class_get_bitmap(class: &Il2CppClass, bitmap: *mut usize);
+    #[optional]
+    pub fn class_set_userdata(class: &Il2CppClass, user_data: *mut c_void);
+    #[optional]
+    pub fn class_get_userdata_offset() -> i32;
+
+    // stats
+    #[optional]
+    pub fn stats_dump_to_file(path: *const c_char) -> bool;
+    #[optional]
+    pub fn stats_get_value(stat: Il2CppStat) -> u64;
+
+    // domain
+    pub fn domain_get() -> &'static Il2CppDomain;
+    #[optional]
+    pub fn domain_assembly_open(domain: &Il2CppDomain, name: *const c_char) -> Option<&'static Il2CppAssembly>;
+    pub fn domain_get_assemblies(domain: &Il2CppDomain, size: &mut usize) -> &'static [&'static Il2CppAssembly];
+
+    // exception
+    pub fn raise_exception(exc: &Il2CppException) -> !;
+    #[optional]
+    pub fn exception_from_name_msg(image: &Il2CppImage, namespace: *const c_char, name: *const c_char, msg: *const c_char) -> &'static mut Il2CppException;
+    #[optional]
+    pub fn get_exception_argument_null(arg: *const c_char) -> &'static mut Il2CppException;
+    #[optional]
+    pub fn format_exception(exc: &Il2CppException, message: *mut c_char, message_size: i32);
+    #[optional]
+    pub fn format_stack_trace(exc: &Il2CppException, output: *mut c_char, output_size: i32);
+    #[optional]
+    pub fn unhandled_exception(exc: &Il2CppException);
+
+    // field
+    #[optional]
+    pub fn field_get_flags(field: &FieldInfo) -> i32;
+    #[optional]
+    pub fn field_get_name(field: &FieldInfo) -> *const c_char;
+    #[optional]
+    pub fn field_get_parent(field: &FieldInfo) -> &'static Il2CppClass;
+    #[optional]
+    pub fn field_get_offset(field: &FieldInfo) -> usize;
+    #[optional]
+    pub fn field_get_type(field: &FieldInfo) -> &'static Il2CppType;
     pub fn field_get_value(obj: &mut Il2CppObject, field: &FieldInfo, value: *mut c_void);
-    pub fn method_get_object(method: &MethodInfo, refclass: Option<&Il2CppClass>) -> &'static mut Il2CppReflectionMethod;
+    #[optional]
+    pub fn field_get_value_object(field: &FieldInfo, obj: Option<&mut Il2CppObject>) -> Option<&'static mut Il2CppObject>;
+    #[optional]
+    pub fn field_has_attribute(field: &FieldInfo, attr_class: &Il2CppClass) -> bool;
+    pub fn field_set_value(obj: &mut Il2CppObject, field: &FieldInfo, value: *const c_void);
+    pub fn field_static_get_value(field: &FieldInfo, value: *mut c_void);
+    pub fn field_static_set_value(field: &FieldInfo, value: *const c_void);
+    #[optional]
+    pub fn field_set_value_object(instance: &mut Il2CppObject, field: &FieldInfo, value: Option<&Il2CppObject>);
+    #[optional]
+    pub fn field_is_literal(field: &FieldInfo) -> bool;
+
+    // gc
+    #[optional]
+    pub fn gc_collect(max_generations: i32);
+    #[optional]
+    pub fn gc_collect_a_little() -> i32;
+    #[optional]
+    pub fn gc_disable();
+    #[optional]
+    pub fn gc_enable();
+    #[optional]
+    pub fn gc_is_disabled() -> bool;
+    #[optional]
+    pub fn gc_get_used_size() -> i64;
+    #[optional]
+    pub fn gc_get_heap_size() -> i64;
+    #[optional]
+    pub fn gc_wbarrier_set_field(obj: &mut Il2CppObject, target_address: *mut *mut c_void, object: *mut c_void);
+    #[optional]
+    pub fn gc_has_strict_wbarriers() -> bool;
+    #[optional]
+    pub fn gc_set_external_allocation_tracker(func: Option<unsafe extern "C" fn(ptr: *mut c_void, size: usize, info: i32)>);
+    #[optional]
+    pub fn gc_set_external_wbarrier_tracker(func: Option<unsafe extern "C" fn(ptr: *mut *mut c_void)>);
+    #[optional]
+    pub fn gc_foreach_heap(func: Option<unsafe extern "C" fn(data: *mut c_void, user_data: *mut c_void)>, user_data: *mut c_void);
+    #[optional]
+    pub fn stop_gc_world();
+    #[optional]
+    pub fn start_gc_world();
+
+    // gchandle
+    pub fn gchandle_new(obj: &Il2CppObject, pinned: bool) -> u32;
+    pub fn gchandle_new_weakref(obj: &Il2CppObject, track_resurrection: bool) -> u32;
+    pub fn gchandle_get_target(handle: u32) -> Option<&'static mut Il2CppObject>;
+    pub fn gchandle_free(handle: u32);
+    #[optional]
+    pub fn gchandle_foreach_get_target(func: Option<unsafe extern "C" fn(data: *mut c_void, user_data: *mut c_void)>, user_data: *mut c_void);
+
+    // vm runtime info
+    #[optional]
+    pub fn object_header_size() -> u32;
+    #[optional]
+    pub fn array_object_header_size() -> u32;
+    #[optional]
+    pub fn offset_of_array_length_in_array_object_header() -> u32;
+    #[optional]
+    pub fn offset_of_array_bounds_in_array_object_header() -> u32;
+    #[optional]
+    pub fn allocation_granularity() -> u32;
+
+    // liveness
+    #[optional]
+    pub fn unity_liveness_calculation_begin(filter: Option<&Il2CppClass>, max_object_count: i32, callback: il2cpp_register_object_callback, user_data: *mut c_void, on_world_started: il2cpp_WorldChangedCallback, on_world_stopped: il2cpp_WorldChangedCallback) -> *mut c_void;
+    #[optional]
+    pub fn unity_liveness_calculation_end(state: *mut c_void);
+    #[optional]
+    pub fn unity_liveness_calculation_from_root(root: &mut Il2CppObject, state: *mut c_void);
+    #[optional]
+    pub fn unity_liveness_calculation_from_statics(state: *mut c_void);
+
+    // method
+    #[optional]
+    pub fn method_get_return_type(method: &MethodInfo) -> &'static Il2CppType;
+    #[optional]
+    pub fn method_get_declaring_type(method: &MethodInfo) -> &'static Il2CppClass;
+    #[optional]
+    pub fn method_get_name(method: &MethodInfo) -> *const c_char;
     pub fn method_get_from_reflection(method: &Il2CppReflectionMethod) -> &'static MethodInfo;
+    pub fn method_get_object(method: &MethodInfo, refclass: Option<&Il2CppClass>) -> &'static mut Il2CppReflectionMethod;
     pub fn method_is_generic(method: &MethodInfo) -> bool;
-    pub fn array_new(element_class: &Il2CppClass, length: usize) -> Option<&'static mut Il2CppArray>;
-    pub fn array_class_get(element_class: &Il2CppClass, rank: u32) -> &'static Il2CppClass;
-    pub fn type_get_name(ty: &Il2CppType) -> *const c_char;
-    pub fn type_get_object(ty: &Il2CppType) -> &'static mut Il2CppReflectionType;
+    pub fn method_is_inflated(method: &MethodInfo) -> bool;
+    #[optional]
+    pub fn method_is_instance(method: &MethodInfo) -> bool;
+    #[optional]
+    pub fn method_get_param_count(method: &MethodInfo) -> u32;
+    #[optional]
+    pub fn method_get_param(method: &MethodInfo, index: u32) -> Option<&'static Il2CppType>;
+    #[optional]
+    pub fn method_get_class(method: &MethodInfo) -> &'static Il2CppClass;
+    #[optional]
+    pub fn method_has_attribute(method: &MethodInfo, attr_class: &Il2CppClass) -> bool;
+    #[optional]
+    pub fn method_get_flags(method: &MethodInfo, iflags: Option<&mut u32>) -> u32;
+    #[optional]
+    pub fn method_get_token(method: &MethodInfo) -> u32;
+    #[optional]
+    pub fn method_get_param_name(method: &MethodInfo, index: u32) -> *const c_char;
+
+    // profiler
+    #[optional]
+    pub fn profiler_install(prof: *mut Il2CppProfiler, shutdown_callback: Il2CppProfileFunc);
+    #[optional]
+    pub fn profiler_set_events(events: Il2CppProfileFlags);
+    #[optional]
+    pub fn profiler_install_enter_leave(enter: Il2CppProfileMethodFunc, leave: Il2CppProfileMethodFunc);
+    #[optional]
+    pub fn profiler_install_allocation(callback: Il2CppProfileAllocFunc);
+    #[optional]
+    pub fn profiler_install_gc(callback: Il2CppProfileGCFunc, heap_resize_callback: Il2CppProfileGCResizeFunc);
+    #[optional]
+    pub fn profiler_install_fileio(callback: Il2CppProfileFileIOFunc);
+    #[optional]
+    pub fn profiler_install_thread(start: Il2CppProfileThreadFunc, end: Il2CppProfileThreadFunc);
+
+    // property
+    #[optional]
+    pub fn property_get_flags(prop: &PropertyInfo) -> u32;
+    #[optional]
+    pub fn property_get_get_method(prop: &PropertyInfo) -> Option<&'static MethodInfo>;
+    #[optional]
+    pub fn property_get_set_method(prop: &PropertyInfo) -> Option<&'static MethodInfo>;
+    #[optional]
+    pub fn property_get_name(prop: &PropertyInfo) -> *const c_char;
+    #[optional]
+    pub fn property_get_parent(prop: &PropertyInfo) -> &'static Il2CppClass;
+
+    // object
+    #[optional]
+    pub fn object_get_class(obj: &Il2CppObject) -> &'static Il2CppClass;
+    #[optional]
+    pub fn object_get_size(obj: &Il2CppObject) -> u32;
+    pub fn object_get_virtual_method(obj: &Il2CppObject, method: &MethodInfo) -> Option<&'static MethodInfo>;
+    pub fn object_new(class: &Il2CppClass) -> &'static mut Il2CppObject;
+    #[optional]
+    pub fn object_unbox(obj: &mut Il2CppObject) -> *mut c_void;
+    #[optional]
+    pub fn value_box(class: &Il2CppClass, data: *mut c_void) -> Option<&'static mut Il2CppObject>;
+
+    // monitor
+    #[optional]
+    pub fn monitor_enter(obj: &Il2CppObject);
+    #[optional]
+    pub fn monitor_try_enter(obj: &Il2CppObject, timeout: u32) -> bool;
+    #[optional]
+    pub fn monitor_exit(obj: &Il2CppObject);
+    #[optional]
+    pub fn monitor_pulse(obj: &Il2CppObject);
+    #[optional]
+    pub fn monitor_pulse_all(obj: &Il2CppObject);
+    #[optional]
+    pub fn monitor_wait(obj: &Il2CppObject);
+    #[optional]
+    pub fn monitor_try_wait(obj: &Il2CppObject, timeout: u32) -> bool;
+
+    // runtime
     pub fn runtime_invoke(method: &MethodInfo, instance: *mut c_void, params: *mut *mut c_void, exception: &mut Option<&mut Il2CppException>) -> Option<&'static mut Il2CppObject>;
+    #[optional]
+    pub fn runtime_invoke_convert_args(method: &MethodInfo, instance: *mut c_void, params: *mut *mut Il2CppObject, param_count: i32, exception: &mut Option<&mut Il2CppException>) -> Option<&'static mut Il2CppObject>;
+    // `il2cpp_class_init` isn't exported, this initializes the class as well as
+    // running its static constructor
+    pub fn runtime_class_init(class: &Il2CppClass);
+    #[optional]
+    pub fn runtime_object_init(obj: &mut Il2CppObject);
+    #[optional]
+    pub fn runtime_object_init_exception(obj: &mut Il2CppObject, exception: &mut Option<&mut Il2CppException>);
+    #[optional]
+    pub fn runtime_unhandled_exception_policy_set(value: Il2CppRuntimeUnhandledExceptionPolicy);
+
+    // string
+    #[optional]
+    pub fn string_length(s: &Il2CppString) -> i32;
+    #[optional]
+    pub fn string_chars(s: &mut Il2CppString) -> *mut Il2CppChar;
+    #[optional]
+    pub fn string_new(s: *const c_char) -> &'static mut Il2CppString;
     pub fn string_new_len(s: *const c_char, len: u32) -> &'static mut Il2CppString;
-    pub fn raise_exception(exc: &Il2CppException) -> !;
-    pub fn resolve_icall(name: *const c_char) -> Il2CppMethodPointer;
-    pub fn object_new(class: &Il2CppClass) -> &'static mut Il2CppObject;
+    #[optional]
+    pub fn string_new_utf16(text: *const Il2CppChar, len: i32) -> &'static mut Il2CppString;
+    #[optional]
+    pub fn string_new_wrapper(s: *const c_char) -> &'static mut Il2CppString;
+    #[optional]
+    pub fn string_intern(s: &Il2CppString) -> &'static mut Il2CppString;
+    #[optional]
+    pub fn string_is_interned(s: &Il2CppString) -> Option<&'static mut Il2CppString>;
+
+    // thread
+    #[optional]
+    pub fn thread_current() -> Option<&'static mut Il2CppThread>;
+    #[optional]
+    pub fn thread_attach(domain: &Il2CppDomain) -> &'static mut Il2CppThread;
+    #[optional]
+    pub fn thread_detach(thread: &mut Il2CppThread);
+    #[optional]
+    pub fn thread_get_all_attached_threads(size: &mut usize) -> *mut *mut Il2CppThread;
+    #[optional]
+    pub fn is_vm_thread(thread: Option<&Il2CppThread>) -> bool;
+
+    // stacktrace
+    #[optional]
+    pub fn current_thread_walk_frame_stack(func: Il2CppFrameWalkFunc, user_data: *mut c_void);
+    #[optional]
+    pub fn thread_walk_frame_stack(thread: &Il2CppThread, func: Il2CppFrameWalkFunc, user_data: *mut c_void);
+    #[optional]
+    pub fn current_thread_get_top_frame(frame: &mut Il2CppStackFrameInfo) -> bool;
+    #[optional]
+    pub fn thread_get_top_frame(thread: &Il2CppThread, frame: &mut Il2CppStackFrameInfo) -> bool;
+    #[optional]
+    pub fn current_thread_get_frame_at(offset: i32, frame: &mut Il2CppStackFrameInfo) -> bool;
+    #[optional]
+    pub fn thread_get_frame_at(thread: &Il2CppThread, offset: i32, frame: &mut Il2CppStackFrameInfo) -> bool;
+    #[optional]
+    pub fn current_thread_get_stack_depth() -> i32;
+    #[optional]
+    pub fn thread_get_stack_depth(thread: &Il2CppThread) -> i32;
+    #[optional]
+    pub fn override_stack_backtrace(method: Option<unsafe extern "C" fn(buffer: *mut Il2CppMethodPointer, max_size: usize) -> usize>);
+
+    // type
+    pub fn type_get_object(ty: &Il2CppType) -> &'static mut Il2CppReflectionType;
+    #[optional]
+    pub fn type_get_type(ty: &Il2CppType) -> i32;
+    #[optional]
+    pub fn type_get_class_or_element_class(ty: &Il2CppType) -> &'static Il2CppClass;
+    pub fn type_get_name(ty: &Il2CppType) -> *const c_char;
+    #[optional]
+    pub fn type_get_name_chunked(ty: &Il2CppType, callback: Option<unsafe extern "C" fn(data: *mut c_void, user_data: *mut c_void)>, user_data: *mut c_void);
+    #[optional]
+    pub fn type_is_byref(ty: &Il2CppType) -> bool;
+    #[optional]
+    pub fn type_get_attrs(ty: &Il2CppType) -> u32;
+    #[optional]
+    pub fn type_equals(ty: &Il2CppType, other_ty: &Il2CppType) -> bool;
+    #[optional]
+    pub fn type_get_assembly_qualified_name(ty: &Il2CppType) -> *const c_char;
+    #[optional]
+    pub fn type_is_static(ty: &Il2CppType) -> bool;
+    #[optional]
+    pub fn type_is_pointer_type(ty: &Il2CppType) -> bool;
+
+    // image
+    #[optional]
+    pub fn image_get_assembly(image: &Il2CppImage) -> &'static Il2CppAssembly;
+    #[optional]
+    pub fn image_get_name(image: &Il2CppImage) -> *const c_char;
+    #[optional]
+    pub fn image_get_filename(image: &Il2CppImage) -> *const c_char;
+    #[optional]
+    pub fn image_get_entry_point(image: &Il2CppImage) -> Option<&'static MethodInfo>;
+    #[optional]
+    pub fn image_get_class_count(image: &Il2CppImage) -> usize;
+    #[optional]
+    pub fn image_get_class(image: &Il2CppImage, index: usize) -> &'static Il2CppClass;
+
+    // memory information
+    #[optional]
+    pub fn capture_memory_snapshot() -> *mut Il2CppManagedMemorySnapshot;
+    #[optional]
+    pub fn free_captured_memory_snapshot(snapshot: *mut Il2CppManagedMemorySnapshot);
+    #[optional]
+    pub fn set_find_plugin_callback(method: Il2CppSetFindPlugInCallback);
+
+    // logging
+    #[optional]
+    pub fn register_log_callback(method: Il2CppLogCallback);
+
+    // debugger
+    #[optional]
+    pub fn debugger_set_agent_options(options: *const c_char);
+    #[optional]
+    pub fn is_debugger_attached() -> bool;
+    #[optional]
+    pub fn register_debugger_agent_transport(debugger_transport: *mut c_void);
+    #[optional]
+    pub fn debug_get_method_info(method: &MethodInfo, method_debug_info: *mut c_void) -> bool;
+
+    // tls module
+    #[optional]
+    pub fn unity_install_unitytls_interface(unitytls_interface_struct: *const c_void);
+
+    // custom attributes
+    #[optional]
+    pub fn custom_attrs_from_class(class: &Il2CppClass) -> Option<&'static mut Il2CppCustomAttrInfo>;
+    #[optional]
+    pub fn custom_attrs_from_method(method: &MethodInfo) -> Option<&'static mut Il2CppCustomAttrInfo>;
+    #[optional]
+    pub fn custom_attrs_get_attr(info: &mut Il2CppCustomAttrInfo, attr_class: &Il2CppClass) -> Option<&'static mut Il2CppObject>;
+    #[optional]
+    pub fn custom_attrs_has_attr(info: &mut Il2CppCustomAttrInfo, attr_class: &Il2CppClass) -> bool;
+    #[optional]
+    pub fn custom_attrs_construct(info: &mut Il2CppCustomAttrInfo) -> Option<&'static mut Il2CppArray>;
+    #[optional]
+    pub fn custom_attrs_free(info: &mut Il2CppCustomAttrInfo);
 }
@@ -1,14 +1,74 @@
 //! Raw il2cpp types and functions
 //!
 //! This module contains raw C types defined in libil2cpp and raw C functions
-//! dynamically loaded from libil2cpp.so.
+//! dynamically loaded from the il2cpp runtime, see [`runtime`](crate::runtime).
+//! Functions which are not exported by every supported runtime return an
+//! `Option`, which is `None` when the loaded runtime lacks them.
 
 mod functions;
 
-#[cfg_attr(feature = "unity2019", path = "types_2019.rs")]
+#[cfg_attr(
+    any(feature = "unity2019", feature = "dynamic"),
+    path = "types_2019.rs"
+)]
 #[cfg_attr(feature = "unity2018", path = "types_2018.rs")]
+#[cfg_attr(feature = "unity2020", path = "types_2020.rs")]
+#[cfg_attr(feature = "unity2021", path = "types_2021.rs")]
+#[cfg_attr(feature = "unity2022", path = "types_2022.rs")]
 mod types;
 
+/// Raw il2cpp types as laid out by Unity 2018
+///
+/// This module is only available with the `dynamic` feature, where the types
+/// of the parent module are those of Unity 2019. It is used to access the few
+/// types which are laid out differently in Unity 2018.
+#[cfg(feature = "dynamic")]
+#[path = "types_2018.rs"]
+pub mod unity2018;
+
+/// Raw il2cpp types as laid out by Unity 2020
+///
+/// This module is only available with the `dynamic` feature, like
+/// [`unity2018`].
+#[cfg(feature = "dynamic")]
+#[path = "types_2020.rs"]
+pub mod unity2020;
+
+/// Raw il2cpp types as laid out by Unity 2021
+///
+/// This module is only available with the `dynamic` feature, like
+/// [`unity2018`].
+#[cfg(feature = "dynamic")]
+#[path = "types_2021.rs"]
+pub mod unity2021;
+
+/// Raw il2cpp types as laid out by Unity 2022
+///
+/// This module is only available with the `dynamic` feature, like
+/// [`unity2018`].
+#[cfg(feature = "dynamic")]
+#[path = "types_2022.rs"]
+pub mod unity2022;
+
+/// Information about a method parameter
+///
+/// Unity 2021 and later only keep the types of the parameters of a method, so
+/// this is assembled from the method instead of being read from the runtime.
+/// The parameter token isn't available anymore and is always 0.
+#[cfg(any(feature = "unity2021", feature = "unity2022"))]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ParameterInfo {
+    /// Name of the parameter
+    pub name: *const std::os::raw::c_char,
+    /// Position of the parameter, starting from 0
+    pub position: i32,
+    /// Metadata token of the parameter, always 0
+    pub token: u32,
+    /// Type of the parameter
+    pub parameter_type: *const Il2CppType,
+}
+
 pub use functions::*;
 pub use types::*;
 
@@ -0,0 +1,334 @@
+//! Loading of the il2cpp runtime
+//!
+//! Every il2cpp function is resolved from the runtime module up front, the
+//! first time one of them is needed. By default the module is looked up by
+//! its usual name for the platform, but [`init`] can be called beforehand to
+//! load it from a different path or to reuse a handle to an already loaded
+//! module, and to handle a failure to load it gracefully.
+//!
+//! With the `dynamic` feature, the Unity version the runtime was built with is
+//! also detected when loading it, so that its structures are accessed with the
+//! right layout. Telling Unity 2019 apart from later versions requires reading
+//! the version of the game's `global-metadata.dat`.
+
+use libloading::Library;
+use std::ffi::{c_void, OsStr, OsString};
+#[cfg(feature = "dynamic")]
+use std::fs::File;
+#[cfg(feature = "dynamic")]
+use std::io::{self, Read};
+use std::mem::ManuallyDrop;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::raw::Functions;
+
+/// Default name of the il2cpp runtime module for the platform
+#[cfg(not(windows))]
+pub const DEFAULT_MODULE: &str = "libil2cpp.so";
+/// Default name of the il2cpp runtime module for the platform
+#[cfg(windows)]
+pub const DEFAULT_MODULE: &str = "GameAssembly.dll";
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+struct Runtime {
+    functions: Functions,
+    #[cfg(feature = "dynamic")]
+    version: UnityVersion,
+    /// The module is never closed, as its functions are called for the rest of
+    /// the program and a module given as a handle belongs to the caller
+    _library: ManuallyDrop<Library>,
+}
+
+/// Unity version an il2cpp runtime was built with, as far as the layout of its
+/// structures is concerned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnityVersion {
+    /// Unity 2018
+    Unity2018,
+    /// Unity 2019
+    Unity2019,
+    /// Unity 2020
+    Unity2020,
+    /// Unity 2021
+    Unity2021,
+    /// Unity 2022
+    Unity2022,
+}
+
+/// Module to load the il2cpp runtime from
+#[derive(Debug)]
+pub enum Module {
+    /// Path or name of the module, which is loaded if it isn't already
+    Path(OsString),
+    /// Raw handle to an already loaded module, as returned by `dlopen` or
+    /// `LoadLibrary`
+    ///
+    /// The handle needs to stay valid for the rest of the program, as the
+    /// module is never unloaded.
+    Handle(*mut c_void),
+    /// Already loaded module
+    Library(Library),
+}
+
+/// Possible errors when loading the il2cpp runtime
+#[derive(Debug, thiserror::Error)]
+pub enum LoadError {
+    /// The module could not be loaded
+    #[error("failed to load il2cpp module: {0}")]
+    Open(#[from] libloading::Error),
+
+    /// The module is missing some of the il2cpp exports
+    #[error("il2cpp module is missing exports: {}", .0.join(", "))]
+    MissingExports(Vec<&'static str>),
+
+    /// The runtime was already loaded, either by an earlier call to [`init`]
+    /// or by using il2cpp before calling it
+    #[error("il2cpp runtime already loaded")]
+    AlreadyLoaded,
+
+    /// The version of the game's metadata could not be read to detect the
+    /// Unity version with the `dynamic` feature
+    #[error("failed to read il2cpp metadata version: {0}")]
+    Metadata(#[source] std::io::Error),
+
+    /// The runtime was built with an unsupported Unity version, identified by
+    /// the version of the game's metadata
+    #[error("unsupported il2cpp metadata version {0}")]
+    UnsupportedVersion(i32),
+}
+
+/// Loads the il2cpp runtime from the given module
+///
+/// This needs to be called before using anything il2cpp related to have an
+/// effect. Otherwise, the runtime is loaded from [`DEFAULT_MODULE`] on first
+/// use, panicking if it fails.
+///
+/// # Safety
+/// The module must be a genuine il2cpp runtime, since the functions it
+/// exports are called with the signatures il2cpp declares for them.
+pub unsafe fn init(module: impl Into<Module>) -> Result<(), LoadError> {
+    if is_loaded() {
+        return Err(LoadError::AlreadyLoaded);
+    }
+    let runtime = Runtime::load(module.into())?;
+    // Losing the race leaks the module without closing it
+    if RUNTIME.set(runtime).is_err() {
+        return Err(LoadError::AlreadyLoaded);
+    }
+    Ok(())
+}
+
+/// Whether the il2cpp runtime has been loaded yet
+pub fn is_loaded() -> bool {
+    RUNTIME.get().is_some()
+}
+
+/// Unity version of the il2cpp runtime
+///
+/// This is the version selected by the enabled feature, or the one detected
+/// when loading the runtime with the `dynamic` feature, in which case the
+/// runtime is loaded from [`DEFAULT_MODULE`] if it isn't already.
+pub fn unity_version() -> UnityVersion {
+    #[cfg(feature = "unity2019")]
+    {
+        UnityVersion::Unity2019
+    }
+
+    #[cfg(feature = "unity2018")]
+    {
+        UnityVersion::Unity2018
+    }
+
+    #[cfg(feature = "unity2020")]
+    {
+        UnityVersion::Unity2020
+    }
+
+    #[cfg(feature = "unity2021")]
+    {
+        UnityVersion::Unity2021
+    }
+
+    #[cfg(feature = "unity2022")]
+    {
+        UnityVersion::Unity2022
+    }
+
+    #[cfg(feature = "dynamic")]
+    {
+        runtime().version
+    }
+}
+
+pub(crate) fn functions() -> &'static Functions {
+    &runtime().functions
+}
+
+fn runtime() -> &'static Runtime {
+    RUNTIME.get_or_init(|| match unsafe { Runtime::load(Module::default()) } {
+        Ok(runtime) => runtime,
+        Err(error) => panic!("{}", error),
+    })
+}
+
+impl Runtime {
+    unsafe fn load(module: Module) -> Result<Self, LoadError> {
+        let borrowed = matches!(module, Module::Handle(_));
+        let library = ManuallyDrop::new(module.open()?);
+        let functions = match Functions::load(&library) {
+            Ok(functions) => functions,
+            Err(error) => {
+                // Closing a module given as a handle would release the
+                // caller's reference to it
+                if !borrowed {
+                    drop(ManuallyDrop::into_inner(library));
+                }
+                return Err(error);
+            }
+        };
+        Ok(Self {
+            #[cfg(feature = "dynamic")]
+            version: UnityVersion::detect(&functions)?,
+            functions,
+            _library: library,
+        })
+    }
+}
+
+impl UnityVersion {
+    #[cfg(feature = "dynamic")]
+    fn detect(functions: &Functions) -> Result<Self, LoadError> {
+        // `Il2CppClass::unity_user_data` was introduced in Unity 2019, along
+        // with the exports to access it
+        if functions.class_get_userdata_offset.is_none() {
+            return Ok(Self::Unity2018);
+        }
+
+        // Later versions keep the exports but change the layouts, which only
+        // shows in the metadata version, 24 up to Unity 2019 and 27 or more
+        // from Unity 2020.2 on. Versions are matched by layout rather than by
+        // release, Unity 2021.1 still using version 27 and the 2021 layouts
+        // only coming with version 29 from Unity 2021.2 on
+        match metadata_version().map_err(LoadError::Metadata)? {
+            24 => Ok(Self::Unity2019),
+            27 | 28 => Ok(Self::Unity2020),
+            29 | 30 => Ok(Self::Unity2021),
+            31 => Ok(Self::Unity2022),
+            version => Err(LoadError::UnsupportedVersion(version)),
+        }
+    }
+}
+
+/// Reads the version from the header of the game's `global-metadata.dat`
+#[cfg(feature = "dynamic")]
+fn metadata_version() -> io::Result<i32> {
+    const SANITY: u32 = 0xFAB1_1BAF;
+
+    let mut header = [0; 8];
+    File::open(metadata_path()?)?.read_exact(&mut header)?;
+    let (sanity, version) = header.split_at(4);
+    if u32::from_le_bytes(sanity.try_into().unwrap()) != SANITY {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "not an il2cpp metadata file",
+        ));
+    }
+    Ok(i32::from_le_bytes(version.try_into().unwrap()))
+}
+
+/// Path of the game's `global-metadata.dat`, which Unity extracts to the
+/// external files directory of the app on Android
+#[cfg(all(feature = "dynamic", target_os = "android"))]
+fn metadata_path() -> io::Result<PathBuf> {
+    let cmdline = std::fs::read("/proc/self/cmdline")?;
+    // The process name is the package name, followed by `:<name>` for
+    // secondary processes
+    let name = cmdline.split(|&b| b == 0).next().unwrap_or_default();
+    let package = String::from_utf8_lossy(name);
+    let package = package.split(':').next().unwrap_or_default();
+    Ok(PathBuf::from(format!(
+        "/sdcard/Android/data/{}/files/il2cpp/Metadata/global-metadata.dat",
+        package
+    )))
+}
+
+/// Path of the game's `global-metadata.dat`, which is in the `<game>_Data`
+/// directory next to the executable on desktop platforms
+#[cfg(all(feature = "dynamic", not(target_os = "android")))]
+fn metadata_path() -> io::Result<PathBuf> {
+    let exe = std::env::current_exe()?;
+    let mut data = exe.with_extension("").into_os_string();
+    data.push("_Data");
+    Ok(Path::new(&data).join("il2cpp_data/Metadata/global-metadata.dat"))
+}
+
+impl Module {
+    unsafe fn open(self) -> Result<Library, LoadError> {
+        Ok(match self {
+            Self::Path(path) => Library::new(path)?,
+            #[cfg(unix)]
+            Self::Handle(handle) => libloading::os::unix::Library::from_raw(handle).into(),
+            #[cfg(windows)]
+            Self::Handle(handle) => {
+                libloading::os::windows::Library::from_raw(handle.cast()).into()
+            }
+            Self::Library(library) => library,
+        })
+    }
+}
+
+impl Default for Module {
+    fn default() -> Self {
+        Self::Path(DEFAULT_MODULE.into())
+    }
+}
+
+impl From<&str> for Module {
+    fn from(path: &str) -> Self {
+        Self::Path(path.into())
+    }
+}
+
+impl From<String> for Module {
+    fn from(path: String) -> Self {
+        Self::Path(path.into())
+    }
+}
+
+impl From<&OsStr> for Module {
+    fn from(path: &OsStr) -> Self {
+        Self::Path(path.into())
+    }
+}
+
+impl From<OsString> for Module {
+    fn from(path: OsString) -> Self {
+        Self::Path(path)
+    }
+}
+
+impl From<&Path> for Module {
+    fn from(path: &Path) -> Self {
+        Self::Path(path.into())
+    }
+}
+
+impl From<PathBuf> for Module {
+    fn from(path: PathBuf) -> Self {
+        Self::Path(path.into())
+    }
+}
+
+impl From<*mut c_void> for Module {
+    fn from(handle: *mut c_void) -> Self {
+        Self::Handle(handle)
+    }
+}
+
+impl From<Library> for Module {
+    fn from(library: Library) -> Self {
+        Self::Library(library)
+    }
+}
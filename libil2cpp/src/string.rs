@@ -4,18 +4,28 @@ use std::ops::{Deref, DerefMut};
 use std::str::FromStr;
 use std::string::FromUtf16Error;
 
-use crate::{raw, Il2CppObject, WrapRaw};
+use crate::{raw, GcHandle, Il2CppObject, WrapRaw};
 
 /// An il2cpp string
 #[repr(transparent)]
 pub struct Il2CppString(raw::Il2CppString);
 
 impl Il2CppString {
-    /// Creates a new string from a Rust string
-    pub fn new(s: impl AsRef<str>) -> &'static mut Self {
+    /// Creates a new string from a Rust string, rooted by a [`GcHandle`]
+    pub fn new(s: impl AsRef<str>) -> GcHandle<Self> {
+        unsafe { GcHandle::new(Self::new_unrooted(s)) }
+    }
+
+    /// Creates a new string from a Rust string, without rooting it
+    ///
+    /// # Safety
+    /// Nothing prevents the string from being collected, so the returned
+    /// reference must not be used after the next garbage collection unless the
+    /// string is rooted by other means
+    pub unsafe fn new_unrooted<'a>(s: impl AsRef<str>) -> &'a mut Self {
         let b = s.as_ref().as_bytes();
-        let s = unsafe { raw::string_new_len(b.as_ptr().cast(), b.len() as _) };
-        unsafe { Self::wrap_mut(s) }
+        let s = raw::string_new_len(b.as_ptr().cast(), b.len() as _);
+        Self::wrap_mut(s)
     }
 
     /// Converts the string to a Rust string, returning an error if its utf-16
@@ -74,7 +84,7 @@ impl AsMut<[u16]> for Il2CppString {
     }
 }
 
-impl<T> From<T> for &'static mut Il2CppString
+impl<T> From<T> for GcHandle<Il2CppString>
 where
     T: AsRef<str>,
 {
@@ -83,7 +93,7 @@ where
     }
 }
 
-impl FromStr for &'static mut Il2CppString {
+impl FromStr for GcHandle<Il2CppString> {
     type Err = Infallible;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -122,12 +132,12 @@ impl fmt::Display for Il2CppString {
 
 #[cfg(feature = "serde")]
 mod serde {
-    use super::Il2CppString;
+    use super::{GcHandle, Il2CppString};
 
     use serde::de::{Deserialize, Deserializer};
     use serde::ser::{Serialize, Serializer};
 
-    impl<'de> Deserialize<'de> for &mut Il2CppString {
+    impl<'de> Deserialize<'de> for GcHandle<Il2CppString> {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
             D: Deserializer<'de>,
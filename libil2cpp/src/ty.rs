@@ -16,6 +16,9 @@ use crate::raw::{
 };
 use crate::{raw, Generics, Il2CppClass, Il2CppException, Il2CppObject, WrapRaw};
 
+#[cfg(feature = "dynamic")]
+use crate::runtime::{unity_version, UnityVersion};
+
 /// An il2cpp type
 #[repr(transparent)]
 pub struct Il2CppType(raw::Il2CppType);
@@ -42,13 +45,62 @@ impl Il2CppType {
 
     /// Whether the type is a ref type
     pub fn is_ref(&self) -> bool {
-        self.raw().byref() != 0
+        #[cfg(not(feature = "dynamic"))]
+        {
+            self.raw().byref() != 0
+        }
+
+        // Unity 2021 shrinks `num_mods` to make room for `valuetype`, which
+        // moves `byref` down a bit
+        #[cfg(feature = "dynamic")]
+        {
+            match unity_version() {
+                UnityVersion::Unity2018 | UnityVersion::Unity2019 | UnityVersion::Unity2020 => {
+                    self.raw().byref() != 0
+                }
+                UnityVersion::Unity2021 | UnityVersion::Unity2022 => self.raw_2021().byref() != 0,
+            }
+        }
+    }
+
+    /// Whether the type is the type of an `out` parameter
+    pub fn is_out(&self) -> bool {
+        self.is_ref() && self.attrs() & raw::PARAM_ATTRIBUTE_OUT != 0
+    }
+
+    /// Attributes of the type, which are those of the field or parameter it
+    /// is the type of, laid out the same by every Unity version
+    pub(crate) fn attrs(&self) -> u32 {
+        self.raw().attrs()
+    }
+
+    /// Whether the type has the given name, which can either be the C# keyword
+    /// or the fully qualified name for builtins, and which must end with `&`
+    /// for ref types
+    pub fn is_named(&self, name: &str) -> bool {
+        let (name, is_ref) = match name.strip_suffix('&') {
+            Some(name) => (name.trim_end(), true),
+            None => (name, false),
+        };
+        if is_ref != self.is_ref() {
+            return false;
+        }
+
+        if let Some(builtin) = self.as_builtin() {
+            return builtin.name() == name || builtin.full_name() == name;
+        }
+        self.name().trim_end_matches('&') == name
     }
 
     /// [`Il2CppReflectionType`] which represents the type
     pub fn reflection_object(&self) -> &Il2CppReflectionType {
         unsafe { Il2CppReflectionType::wrap_mut(raw::type_get_object(self.raw())) }
     }
+
+    #[cfg(feature = "dynamic")]
+    fn raw_2021(&self) -> &raw::unity2021::Il2CppType {
+        unsafe { &*(self as *const Self).cast() }
+    }
 }
 
 unsafe impl WrapRaw for Il2CppType {
@@ -57,7 +109,26 @@ unsafe impl WrapRaw for Il2CppType {
 
 impl PartialEq for Il2CppType {
     fn eq(&self, other: &Self) -> bool {
-        unsafe { self.raw().data.klassIndex == other.raw().data.klassIndex }
+        #[cfg(any(feature = "unity2019", feature = "unity2018"))]
+        unsafe {
+            self.raw().data.klassIndex == other.raw().data.klassIndex
+        }
+
+        // The class index became a pointer to the class metadata in Unity 2020
+        #[cfg(any(feature = "unity2020", feature = "unity2021", feature = "unity2022"))]
+        unsafe {
+            self.raw().data.typeHandle == other.raw().data.typeHandle
+        }
+
+        #[cfg(feature = "dynamic")]
+        match unity_version() {
+            UnityVersion::Unity2018 | UnityVersion::Unity2019 => unsafe {
+                self.raw().data.klassIndex == other.raw().data.klassIndex
+            },
+            UnityVersion::Unity2020 | UnityVersion::Unity2021 | UnityVersion::Unity2022 => unsafe {
+                self.raw().data.dummy == other.raw().data.dummy
+            },
+        }
     }
 }
 impl Eq for Il2CppType {}
@@ -77,10 +148,10 @@ impl fmt::Display for Il2CppType {
 }
 
 macro_rules! builtins {
-    ($($const:ident => ($variant:ident, $id:ident, $name:literal),)*) => {
+    ($($const:ident => ($variant:ident, $id:ident, $name:literal, $full_name:literal),)*) => {
         #[doc = "Builtin C# types"]
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-        #[cfg_attr(feature = "unity2019", repr(u32))]
+        #[cfg_attr(not(feature = "unity2018"), repr(u32))]
         #[cfg_attr(feature = "unity2018", repr(i32))]
         pub enum Builtin {
             $(
@@ -93,7 +164,7 @@ macro_rules! builtins {
             #[doc = "Whether the type represents the given [`Builtin`]"]
             #[inline]
             pub fn is_builtin(&self, builtin: Builtin) -> bool {
-                #[cfg(feature = "unity2019")]
+                #[cfg(not(feature = "unity2018"))]
                 { self.raw().type_() == builtin as u32 }
 
                 #[cfg(feature = "unity2018")]
@@ -120,32 +191,39 @@ macro_rules! builtins {
         }
 
         impl Builtin {
-            #[doc = "Name of the builtin"]
+            #[doc = "C# keyword of the builtin"]
             pub fn name(self) -> &'static str {
                 match self {
                     $(Self::$variant => $name,)*
                 }
             }
+
+            #[doc = "Fully qualified name of the builtin"]
+            pub fn full_name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $full_name,)*
+                }
+            }
         }
     }
 }
 
 builtins! {
-    Il2CppTypeEnum_IL2CPP_TYPE_VOID => (Void, void, "void"),
-    Il2CppTypeEnum_IL2CPP_TYPE_OBJECT => (Object, object, "object"),
-    Il2CppTypeEnum_IL2CPP_TYPE_BOOLEAN => (Bool, bool, "bool"),
-    Il2CppTypeEnum_IL2CPP_TYPE_CHAR => (Char, char, "char"),
-    Il2CppTypeEnum_IL2CPP_TYPE_U1 => (Byte, byte, "byte"),
-    Il2CppTypeEnum_IL2CPP_TYPE_I1 => (SByte, sbyte, "sbyte"),
-    Il2CppTypeEnum_IL2CPP_TYPE_I2 => (Short, short, "short"),
-    Il2CppTypeEnum_IL2CPP_TYPE_U2 => (UShort, ushort, "ushort"),
-    Il2CppTypeEnum_IL2CPP_TYPE_I4 => (Int, int, "int"),
-    Il2CppTypeEnum_IL2CPP_TYPE_U4 => (UInt, uint, "uint"),
-    Il2CppTypeEnum_IL2CPP_TYPE_I8 => (Long, long, "long"),
-    Il2CppTypeEnum_IL2CPP_TYPE_U8 => (ULong, ulong, "ulong"),
-    Il2CppTypeEnum_IL2CPP_TYPE_R4 => (Single, single, "single"),
-    Il2CppTypeEnum_IL2CPP_TYPE_R8 => (Double, double, "double"),
-    Il2CppTypeEnum_IL2CPP_TYPE_STRING => (String, string, "string"),
+    Il2CppTypeEnum_IL2CPP_TYPE_VOID => (Void, void, "void", "System.Void"),
+    Il2CppTypeEnum_IL2CPP_TYPE_OBJECT => (Object, object, "object", "System.Object"),
+    Il2CppTypeEnum_IL2CPP_TYPE_BOOLEAN => (Bool, bool, "bool", "System.Boolean"),
+    Il2CppTypeEnum_IL2CPP_TYPE_CHAR => (Char, char, "char", "System.Char"),
+    Il2CppTypeEnum_IL2CPP_TYPE_U1 => (Byte, byte, "byte", "System.Byte"),
+    Il2CppTypeEnum_IL2CPP_TYPE_I1 => (SByte, sbyte, "sbyte", "System.SByte"),
+    Il2CppTypeEnum_IL2CPP_TYPE_I2 => (Short, short, "short", "System.Int16"),
+    Il2CppTypeEnum_IL2CPP_TYPE_U2 => (UShort, ushort, "ushort", "System.UInt16"),
+    Il2CppTypeEnum_IL2CPP_TYPE_I4 => (Int, int, "int", "System.Int32"),
+    Il2CppTypeEnum_IL2CPP_TYPE_U4 => (UInt, uint, "uint", "System.UInt32"),
+    Il2CppTypeEnum_IL2CPP_TYPE_I8 => (Long, long, "long", "System.Int64"),
+    Il2CppTypeEnum_IL2CPP_TYPE_U8 => (ULong, ulong, "ulong", "System.UInt64"),
+    Il2CppTypeEnum_IL2CPP_TYPE_R4 => (Single, single, "float", "System.Single"),
+    Il2CppTypeEnum_IL2CPP_TYPE_R8 => (Double, double, "double", "System.Double"),
+    Il2CppTypeEnum_IL2CPP_TYPE_STRING => (String, string, "string", "System.String"),
 }
 
 impl fmt::Display for Builtin {
@@ -173,7 +251,15 @@ impl Il2CppReflectionType {
     where
         G: Generics,
     {
-        let generics = G::type_array();
+        self.make_generic_from(G::type_array())
+    }
+
+    /// Instanciates a generic type template with an array of
+    /// `System.RuntimeType`s as generic arguments
+    pub(crate) fn make_generic_from(
+        &self,
+        generics: &mut raw::Il2CppArray,
+    ) -> Result<Option<&Self>, &'static mut Il2CppException> {
         let make_generic = self
             .class()
             .find_method_unchecked("MakeGenericType", 2)
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::{Builtin, Il2CppType, MethodInfo, Type};
+use crate::{Builtin, Il2CppError, Il2CppType, MethodInfo, Type};
 
 /// Trait implemented by types that can be used as C# `this` method parameters
 ///
@@ -14,6 +14,15 @@ pub unsafe trait ThisParameter {
     /// Type of the actual `this` parameter
     type Actual;
 
+    /// Looks up the classes needed to type check the type, returning an error
+    /// if one of them can't be found
+    ///
+    /// The `try_` methods call this first, since [`matches`](Self::matches)
+    /// panics if a class is missing.
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        Ok(())
+    }
+
     /// Checks whether the type can be used as a C# instance parameter for the
     /// given [`MethodInfo`]
     fn matches(method: &MethodInfo) -> bool;
@@ -36,10 +45,24 @@ pub unsafe trait Parameter {
     /// Type of the actual parameter
     type Actual;
 
+    /// Looks up the classes needed to type check the type, returning an error
+    /// if one of them can't be found
+    ///
+    /// The `try_` methods call this first, since [`matches`](Self::matches)
+    /// panics if a class is missing.
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        Ok(())
+    }
+
     /// Checks whether the type can be used as a C# parameter with the given
     /// [`Il2CppType`]
     fn matches(ty: &Il2CppType) -> bool;
 
+    /// Name of the C# type, used in error messages
+    fn type_name() -> String {
+        std::any::type_name::<Self>().to_owned()
+    }
+
     /// Converts from the actual type to the desired one
     fn from_actual(actual: Self::Actual) -> Self;
     /// Converts from the desired type into the actual one
@@ -59,10 +82,24 @@ pub unsafe trait Return {
     /// Type of the actual return value
     type Actual;
 
+    /// Looks up the classes needed to type check the type, returning an error
+    /// if one of them can't be found
+    ///
+    /// The `try_` methods call this first, since [`matches`](Self::matches)
+    /// panics if a class is missing.
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        Ok(())
+    }
+
     /// Checks whether the type can be used as a C# return type of the given
     /// [`Il2CppType`]
     fn matches(ty: &Il2CppType) -> bool;
 
+    /// Name of the C# type, used in error messages
+    fn type_name() -> String {
+        std::any::type_name::<Self>().to_owned()
+    }
+
     /// Converts from the desired type into the actual one
     fn into_actual(self) -> Self::Actual;
     /// Converts from the actual type to the desired one
@@ -81,9 +118,23 @@ pub unsafe trait Parameters {
     /// Parameter count
     const COUNT: usize;
 
+    /// Looks up the classes needed to type check the type, returning an error
+    /// if one of them can't be found
+    ///
+    /// The `try_` methods call this first, since [`matches`](Self::matches)
+    /// panics if a class is missing.
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        Ok(())
+    }
+
     /// Checks whether the type can be used as a C# parameter collection for the
     /// given [`MethodInfo`]
     fn matches(method: &MethodInfo) -> bool;
+
+    /// Names of the C# types, used in error messages
+    fn type_name() -> String {
+        std::any::type_name::<Self>().to_owned()
+    }
 }
 
 unsafe impl<T> ThisParameter for Option<&mut T>
@@ -92,6 +143,10 @@ where
 {
     type Actual = Self;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(method: &MethodInfo) -> bool {
         T::matches_this_parameter(method)
     }
@@ -110,6 +165,10 @@ where
 {
     type Actual = Option<Self>;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(method: &MethodInfo) -> bool {
         T::matches_this_parameter(method)
     }
@@ -145,10 +204,18 @@ where
 {
     type Actual = Self;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(ty: &Il2CppType) -> bool {
         T::matches_reference_parameter(ty)
     }
 
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
     fn from_actual(actual: Self::Actual) -> Self {
         actual
     }
@@ -165,10 +232,18 @@ where
 {
     type Actual = Option<Self>;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(ty: &Il2CppType) -> bool {
         T::matches_reference_parameter(ty)
     }
 
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
     fn from_actual(actual: Self::Actual) -> Self {
         actual.unwrap()
     }
@@ -185,10 +260,18 @@ where
 {
     type Actual = Self;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(ty: &Il2CppType) -> bool {
         T::matches_return(ty)
     }
 
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
     fn into_actual(self) -> Self::Actual {
         self
     }
@@ -205,10 +288,18 @@ where
 {
     type Actual = Option<Self>;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(ty: &Il2CppType) -> bool {
         T::matches_return(ty)
     }
 
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
     fn into_actual(self) -> Self::Actual {
         Some(self)
     }
@@ -224,6 +315,10 @@ unsafe impl Return for () {
         ty.is_builtin(Builtin::Void)
     }
 
+    fn type_name() -> String {
+        "void".to_owned()
+    }
+
     fn into_actual(self) {}
     fn from_actual((): ()) {}
 }
@@ -235,10 +330,18 @@ where
 {
     type Actual = T::Actual;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::resolve()
+    }
+
     fn matches(ty: &Il2CppType) -> bool {
         T::matches(ty)
     }
 
+    fn type_name() -> String {
+        T::type_name()
+    }
+
     fn into_actual(self) -> Self::Actual {
         self.unwrap().into_actual()
     }
@@ -253,6 +356,10 @@ unsafe impl Parameters for () {
     fn matches(method: &MethodInfo) -> bool {
         method.parameters().is_empty()
     }
+
+    fn type_name() -> String {
+        "()".to_owned()
+    }
 }
 
 unsafe impl<P> Parameters for P
@@ -261,8 +368,16 @@ where
 {
     const COUNT: usize = 1;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        P::resolve()
+    }
+
     fn matches(method: &MethodInfo) -> bool {
         let params = method.parameters();
         params.len() == 1 && unsafe { P::matches(params.get_unchecked(0).ty()) }
     }
+
+    fn type_name() -> String {
+        format!("({})", P::type_name())
+    }
 }
@@ -3,7 +3,7 @@ use std::ffi::c_void;
 use std::mem::transmute;
 use std::ptr::null_mut;
 
-use crate::{Builtin, Il2CppObject, Il2CppType, MethodInfo, Type};
+use crate::{Builtin, GcHandle, Il2CppError, Il2CppObject, Il2CppType, MethodInfo, Type};
 
 /// Trait implemented by types that can be used as a C# `this` arguments
 ///
@@ -17,10 +17,24 @@ pub unsafe trait ThisArgument {
     /// Normalized type of `this`, useful for caching
     type Type: Any;
 
+    /// Looks up the classes needed to type check the type, returning an error
+    /// if one of them can't be found
+    ///
+    /// The `try_` methods call this first, since [`matches`](Self::matches)
+    /// panics if a class is missing.
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        Ok(())
+    }
+
     /// Checks whether the type can be used as a C# `this` argument for the
     /// given [`MethodInfo`]
     fn matches(method: &MethodInfo) -> bool;
 
+    /// Name of the C# type, used in error messages
+    fn type_name() -> String {
+        std::any::type_name::<Self>().to_owned()
+    }
+
     /// Returns an untyped pointer which can be used as a libill2cpp `this`
     /// argument
     fn invokable(&mut self) -> *mut c_void;
@@ -38,12 +52,31 @@ pub unsafe trait Argument {
     /// Normalized type of the argument, useful for caching
     type Type: Any;
 
+    /// Looks up the classes needed to type check the type, returning an error
+    /// if one of them can't be found
+    ///
+    /// The `try_` methods call this first, since [`matches`](Self::matches)
+    /// panics if a class is missing.
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        Ok(())
+    }
+
     /// Checks whether the type can be used as a C# argument with the given
     /// [`Il2CppType`] to call a method
     fn matches(ty: &Il2CppType) -> bool;
 
-    /// Returns an untyped pointer which can be used as a libil2cpp argument
-    fn invokable(&mut self) -> *mut c_void;
+    /// Name of the C# type, used in error messages
+    fn type_name() -> String {
+        std::any::type_name::<Self>().to_owned()
+    }
+
+    /// Calls `f` with an untyped pointer which can be used as a libil2cpp
+    /// argument
+    ///
+    /// Types which aren't laid out the way libil2cpp expects, such as
+    /// `Option<T>` for nullable values, pass a pointer to a converted copy of
+    /// themselves which only lives for the duration of the call.
+    fn with_invokable<O>(&mut self, f: impl FnOnce(*mut c_void) -> O) -> O;
 }
 
 /// Trait implemented by types that can be used as return types from C# methods
@@ -58,10 +91,24 @@ pub unsafe trait Returned {
     /// Normalized type of the return type, useful for caching
     type Type: Any;
 
+    /// Looks up the classes needed to type check the type, returning an error
+    /// if one of them can't be found
+    ///
+    /// The `try_` methods call this first, since [`matches`](Self::matches)
+    /// panics if a class is missing.
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        Ok(())
+    }
+
     /// Checks whether the type can be used as a C# return type of the given
     /// [`Il2CppType`]
     fn matches(ty: &Il2CppType) -> bool;
 
+    /// Name of the C# type, used in error messages
+    fn type_name() -> String {
+        std::any::type_name::<Self>().to_owned()
+    }
+
     /// Converts the [`Il2CppObject`] returned by
     /// [`runtime_invoke`](crate::raw::runtime_invoke) into self
     fn from_object(object: Option<&mut Il2CppObject>) -> Self;
@@ -79,13 +126,27 @@ pub unsafe trait Arguments<const N: usize> {
     /// Normalized type of the arguments, useful for caching
     type Type: Any;
 
+    /// Looks up the classes needed to type check the type, returning an error
+    /// if one of them can't be found
+    ///
+    /// The `try_` methods call this first, since [`matches`](Self::matches)
+    /// panics if a class is missing.
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        Ok(())
+    }
+
     /// Checks whether the type can be used as a C# argument collection for the
     /// given [`MethodInfo`]
     fn matches(method: &MethodInfo) -> bool;
 
-    /// Returns an array of untyped pointer which can be used to invoke C#
-    /// methods
-    fn invokable(&mut self) -> [*mut c_void; N];
+    /// Names of the C# types, used in error messages
+    fn type_name() -> String {
+        std::any::type_name::<Self>().to_owned()
+    }
+
+    /// Calls `f` with an array of untyped pointers which can be used to invoke
+    /// C# methods
+    fn with_invokable<O>(&mut self, f: impl FnOnce([*mut c_void; N]) -> O) -> O;
 }
 
 unsafe impl<T> ThisArgument for Option<&mut T>
@@ -94,10 +155,18 @@ where
 {
     type Type = T;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(method: &MethodInfo) -> bool {
         T::matches_this_argument(method)
     }
 
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
     fn invokable(&mut self) -> *mut c_void {
         unsafe { transmute((self as *mut Self).read()) }
     }
@@ -109,10 +178,18 @@ where
 {
     type Type = T;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(method: &MethodInfo) -> bool {
         T::matches_this_argument(method)
     }
 
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
     fn invokable(&mut self) -> *mut c_void {
         (*self as *mut T).cast()
     }
@@ -125,6 +202,10 @@ unsafe impl ThisArgument for () {
         method.is_static()
     }
 
+    fn type_name() -> String {
+        "static".to_owned()
+    }
+
     fn invokable(&mut self) -> *mut c_void {
         null_mut()
     }
@@ -138,12 +219,20 @@ where
 {
     type Type = T;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(ty: &Il2CppType) -> bool {
         T::matches_reference_argument(ty)
     }
 
-    fn invokable(&mut self) -> *mut c_void {
-        unsafe { transmute((self as *mut Self).read()) }
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
+    fn with_invokable<O>(&mut self, f: impl FnOnce(*mut c_void) -> O) -> O {
+        f(unsafe { transmute::<Self, *mut c_void>((self as *mut Self).read()) })
     }
 }
 
@@ -155,12 +244,45 @@ where
 {
     type Type = T;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(ty: &Il2CppType) -> bool {
         T::matches_reference_argument(ty)
     }
 
-    fn invokable(&mut self) -> *mut c_void {
-        (*self as *mut T).cast()
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
+    fn with_invokable<O>(&mut self, f: impl FnOnce(*mut c_void) -> O) -> O {
+        f((*self as *mut T).cast())
+    }
+}
+
+// TODO: Remove this once rustfmt stops dropping generics on GATs
+#[rustfmt::skip]
+unsafe impl<T> Argument for &mut GcHandle<T>
+where
+    T: for<'a> Type<Held<'a> = Option<&'a mut T>>,
+{
+    type Type = T;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        T::matches_reference_argument(ty)
+    }
+
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
+    fn with_invokable<O>(&mut self, f: impl FnOnce(*mut c_void) -> O) -> O {
+        f((&mut ***self as *mut T).cast())
     }
 }
 
@@ -172,10 +294,18 @@ where
 {
     type Type = T;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(ty: &Il2CppType) -> bool {
         T::matches_returned(ty)
     }
 
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
     fn from_object(object: Option<&mut Il2CppObject>) -> Self {
         unsafe { transmute(object) }
     }
@@ -189,10 +319,18 @@ where
 {
     type Type = T;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(ty: &Il2CppType) -> bool {
         T::matches_returned(ty)
     }
 
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
     fn from_object(object: Option<&mut Il2CppObject>) -> Self {
         unsafe { transmute(object) }
     }
@@ -206,10 +344,18 @@ where
 {
     type Type = T;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(ty: &Il2CppType) -> bool {
         T::matches_returned(ty)
     }
 
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
     fn from_object(object: Option<&mut Il2CppObject>) -> Self {
         unsafe { &mut *(object.unwrap() as *mut Il2CppObject).cast() }
     }
@@ -223,15 +369,48 @@ where
 {
     type Type = T;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn matches(ty: &Il2CppType) -> bool {
         T::matches_returned(ty)
     }
 
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
     fn from_object(object: Option<&mut Il2CppObject>) -> Self {
         unsafe { &*(object.unwrap() as *mut Il2CppObject).cast() }
     }
 }
 
+// TODO: Remove this once rustfmt stops dropping generics on GATs
+#[rustfmt::skip]
+unsafe impl<T> Returned for Option<GcHandle<T>>
+where
+    T: for<'a> Type<Held<'a> = Option<&'a mut T>>,
+{
+    type Type = T;
+
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
+    fn matches(ty: &Il2CppType) -> bool {
+        T::matches_returned(ty)
+    }
+
+    fn type_name() -> String {
+        T::class().ty().to_string()
+    }
+
+    fn from_object(object: Option<&mut Il2CppObject>) -> Self {
+        object.map(|o| unsafe { GcHandle::new(&mut *(o as *mut Il2CppObject).cast()) })
+    }
+}
+
 unsafe impl Returned for () {
     type Type = ();
 
@@ -239,6 +418,10 @@ unsafe impl Returned for () {
         ty.is_builtin(Builtin::Void)
     }
 
+    fn type_name() -> String {
+        "void".to_owned()
+    }
+
     fn from_object(_: Option<&mut Il2CppObject>) {}
 }
 
@@ -249,8 +432,12 @@ unsafe impl Arguments<0> for () {
         method.parameters().is_empty()
     }
 
-    fn invokable(&mut self) -> [*mut c_void; 0] {
-        []
+    fn type_name() -> String {
+        "()".to_owned()
+    }
+
+    fn with_invokable<O>(&mut self, f: impl FnOnce([*mut c_void; 0]) -> O) -> O {
+        f([])
     }
 }
 
@@ -260,12 +447,20 @@ where
 {
     type Type = (A::Type,);
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        A::resolve()
+    }
+
     fn matches(method: &MethodInfo) -> bool {
         let params = method.parameters();
         params.len() == 1 && unsafe { A::matches(params.get_unchecked(0).ty()) }
     }
 
-    fn invokable(&mut self) -> [*mut c_void; 1] {
-        [Argument::invokable(self)]
+    fn type_name() -> String {
+        format!("({})", A::type_name())
+    }
+
+    fn with_invokable<O>(&mut self, f: impl FnOnce([*mut c_void; 1]) -> O) -> O {
+        Argument::with_invokable(self, |arg| f([arg]))
     }
 }
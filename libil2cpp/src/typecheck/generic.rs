@@ -1,4 +1,4 @@
-use crate::{raw, Il2CppReflectionType, Type, WrapRaw};
+use crate::{raw, Il2CppClass, Il2CppError, Il2CppReflectionType, Type, WrapRaw};
 
 /// Trait implemented for Rust types which can represent a list of C# generic
 /// arguments
@@ -6,6 +6,15 @@ pub trait Generics {
     /// Number of generic arguments
     const COUNT: usize;
 
+    /// Looks up the classes of the generic arguments, returning an error if
+    /// one of them can't be found
+    ///
+    /// The `try_` methods call this first, since
+    /// [`type_array`](Self::type_array) panics if a class is missing.
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        Ok(())
+    }
+
     /// Returns an array of `System.RuntimeType`s matching the generic arguments
     fn type_array() -> &'static mut raw::Il2CppArray;
 }
@@ -13,6 +22,10 @@ pub trait Generics {
 impl<T: Type> Generics for T {
     const COUNT: usize = 1;
 
+    fn resolve() -> Result<(), Il2CppError<'static>> {
+        T::try_class().map(drop)
+    }
+
     fn type_array() -> &'static mut raw::Il2CppArray {
         let arr = unsafe { raw::array_new(Il2CppReflectionType::class().raw(), 1) }.unwrap();
         unsafe {
@@ -23,3 +36,19 @@ impl<T: Type> Generics for T {
         arr
     }
 }
+
+/// Returns an array of `System.RuntimeType`s matching the given classes, for
+/// generic arguments which are only known at runtime
+pub(crate) fn type_array_of(classes: &[&Il2CppClass]) -> &'static mut raw::Il2CppArray {
+    let arr =
+        unsafe { raw::array_new(Il2CppReflectionType::class().raw(), classes.len()) }.unwrap();
+    for (i, class) in classes.iter().enumerate() {
+        unsafe {
+            (((arr as *mut _ as isize) + (raw::kIl2CppSizeOfArray as isize))
+                as *mut &Il2CppReflectionType)
+                .add(i)
+                .write_unaligned(class.ty().reflection_object());
+        }
+    }
+    arr
+}
@@ -1,6 +1,6 @@
 use crate::{
-    Il2CppClass, Il2CppObject, Il2CppReflectionMethod, Il2CppReflectionType, Il2CppString,
-    Il2CppType, MethodInfo,
+    Il2CppClass, Il2CppError, Il2CppObject, Il2CppReflectionMethod, Il2CppReflectionType,
+    Il2CppString, Il2CppType, MethodInfo,
 };
 
 /// Trait implemented by Rust types that are also C# types
@@ -20,8 +20,28 @@ pub unsafe trait Type: 'static {
     const CLASS_NAME: &'static str;
 
     /// [`Il2CppClass`] of the type
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the class can't be found
     fn class() -> &'static Il2CppClass {
-        Il2CppClass::find(Self::NAMESPACE, Self::CLASS_NAME).unwrap()
+        match Self::try_class() {
+            Ok(class) => class,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// [`Il2CppClass`] of the type, returning an error if it can't be found
+    ///
+    /// Types with a custom way of getting their class should override this
+    /// method, which [`class`](Self::class) is built on.
+    fn try_class() -> Result<&'static Il2CppClass, Il2CppError<'static>> {
+        Il2CppClass::find(Self::NAMESPACE, Self::CLASS_NAME).ok_or_else(|| {
+            Il2CppError::ClassNotFound {
+                namespace: Self::NAMESPACE.to_owned(),
+                name: Self::CLASS_NAME.to_owned(),
+            }
+        })
     }
 
     /// Whether the type can be used as a `this` argument for the given
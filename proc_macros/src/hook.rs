@@ -2,14 +2,16 @@ use heck::{CamelCase, SnakeCase};
 use proc_macro::TokenStream;
 use proc_macro2::{Group, TokenStream as TokenStream2, TokenTree as TokenTree2};
 use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
+use syn::token::Bracket;
 use syn::{
-    Abi, Attribute, Error, FnArg, GenericParam, Ident, ItemFn, LitStr, Pat, PatType, ReturnType,
-    Token, Type, TypeTuple,
+    Abi, Attribute, Error, Expr, ExprArray, ExprLit, FnArg, GenericParam, Ident, ItemFn, Lit,
+    LitStr, Pat, PatType, ReturnType, Token, Type, TypeTuple,
 };
 
-pub fn expand(args: &Punctuated<LitStr, Token![,]>, input: ItemFn) -> Result<TokenStream, Error> {
+pub fn expand(args: &Punctuated<Arg, Token![,]>, input: ItemFn) -> Result<TokenStream, Error> {
     let metadata = Metadata::new(args, input)?;
     metadata.validate()?;
 
@@ -29,38 +31,215 @@ pub fn expand(args: &Punctuated<LitStr, Token![,]>, input: ItemFn) -> Result<Tok
     Ok(ts.into())
 }
 
+pub enum Arg {
+    Positional(LitStr),
+    PositionalList(ExprArray),
+    Named { name: Ident, value: Box<Expr> },
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream<'_>) -> Result<Self, Error> {
+        if input.peek(LitStr) {
+            return Ok(Self::Positional(input.parse()?));
+        }
+        if input.peek(Bracket) {
+            return Ok(Self::PositionalList(input.parse()?));
+        }
+
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+        Ok(Self::Named { name, value })
+    }
+}
+
+impl ToTokens for Arg {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            Self::Positional(lit) => lit.to_tokens(tokens),
+            Self::PositionalList(array) => array.to_tokens(tokens),
+            Self::Named { name, value } => tokens.extend(quote!(#name = #value)),
+        }
+    }
+}
+
 pub struct Metadata {
     namespace: String,
-    class: String,
+    /// Names of the classes, several of which can be given to hook multiple
+    /// instantiations of a generic class
+    classes: Vec<String>,
     method: String,
+    priority: Option<Expr>,
+    signature: Option<Signature>,
     input: ItemFn,
 }
 
-impl Metadata {
-    fn new(args: &Punctuated<LitStr, Token![,]>, input: ItemFn) -> Result<Self, Error> {
-        let mut iter = args.iter().map(LitStr::value);
-
-        macro_rules! parse {
-            () => {
-                match iter.next() {
-                    Some(a) => a,
-                    None => return Err(Error::new_spanned(args, "Expected 3 arguments")),
-                }
-            };
+/// Explicit C# signature used to select between overloads
+struct Signature {
+    params: Vec<String>,
+    return_ty: Option<String>,
+    expr: Expr,
+}
+
+impl Signature {
+    /// Parses a signature of the form `"<return type> (<parameter types>)"`,
+    /// where the return type is optional
+    fn from_signature(expr: &Expr) -> Result<Self, Error> {
+        let lit = match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => lit,
+            _ => return Err(Error::new_spanned(expr, "Expected a string literal")),
+        };
+        let value = lit.value();
+
+        let invalid = || Error::new_spanned(lit, "Expected a signature like `void (int, string)`");
+        let (return_ty, params) = value.split_once('(').ok_or_else(invalid)?;
+        let params = params.trim_end().strip_suffix(')').ok_or_else(invalid)?;
+
+        let return_ty = match return_ty.trim() {
+            "" => None,
+            ty => Some(ty.to_owned()),
+        };
+        let params = split_type_list(params).ok_or_else(invalid)?;
+
+        Ok(Self {
+            params,
+            return_ty,
+            expr: expr.clone(),
+        })
+    }
+
+    /// Parses a list of parameter types of the form `["<type>", ...]`
+    fn from_params(expr: &Expr) -> Result<Self, Error> {
+        let array = match expr {
+            Expr::Array(array) => array,
+            _ => {
+                return Err(Error::new_spanned(
+                    expr,
+                    "Expected an array of string literals",
+                ))
+            }
+        };
+
+        let params = string_list(array)?;
+
+        Ok(Self {
+            params,
+            return_ty: None,
+            expr: expr.clone(),
+        })
+    }
+}
+
+/// Parses an array of string literals
+fn string_list(array: &ExprArray) -> Result<Vec<String>, Error> {
+    array
+        .elems
+        .iter()
+        .map(|e| match e {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => Ok(lit.value().trim().to_owned()),
+            _ => Err(Error::new_spanned(e, "Expected a string literal")),
+        })
+        .collect()
+}
+
+/// Splits a comma separated list of C# types, ignoring commas nested in generic
+/// arguments and array ranks
+fn split_type_list(list: &str) -> Option<Vec<String>> {
+    if list.trim().is_empty() {
+        return Some(Vec::new());
+    }
+
+    let mut types = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in list.char_indices() {
+        match c {
+            '<' | '[' => depth += 1,
+            '>' | ']' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => {
+                types.push(&list[start..i]);
+                start = i + 1;
+            }
+            _ => (),
         }
+    }
+    types.push(&list[start..]);
+
+    types
+        .into_iter()
+        .map(|t| match t.trim() {
+            "" => None,
+            t => Some(t.to_owned()),
+        })
+        .collect()
+}
 
-        let namespace = parse!();
-        let class = parse!();
-        let method = parse!();
+impl Metadata {
+    fn new(args: &Punctuated<Arg, Token![,]>, input: ItemFn) -> Result<Self, Error> {
+        let mut positional: Vec<Vec<String>> = Vec::new();
+        let mut priority = None;
+        let mut signature = None;
 
-        if iter.next().is_some() {
-            return Err(Error::new_spanned(args, "Expected 3 arguments"));
+        for arg in args {
+            match arg {
+                Arg::Positional(lit) => positional.push(vec![lit.value()]),
+                Arg::PositionalList(array) => {
+                    if positional.len() != 1 {
+                        return Err(Error::new_spanned(
+                            array,
+                            "Only the class can be a list of instantiations",
+                        ));
+                    }
+                    let classes = string_list(array)?;
+                    if classes.is_empty() {
+                        return Err(Error::new_spanned(array, "Expected at least one class"));
+                    }
+                    positional.push(classes);
+                }
+                Arg::Named { name, value } if name == "priority" => {
+                    if priority.replace((**value).clone()).is_some() {
+                        return Err(Error::new_spanned(name, "Duplicate `priority` argument"));
+                    }
+                }
+                Arg::Named { name, value } if name == "signature" || name == "params" => {
+                    let parsed = if name == "signature" {
+                        Signature::from_signature(value)?
+                    } else {
+                        Signature::from_params(value)?
+                    };
+                    if signature.replace(parsed).is_some() {
+                        return Err(Error::new_spanned(
+                            name,
+                            "Only one of `signature` or `params` can be provided",
+                        ));
+                    }
+                }
+                Arg::Named { name, .. } => {
+                    return Err(Error::new_spanned(
+                        name,
+                        format!("Unknown argument `{}`", name),
+                    ))
+                }
+            }
         }
 
+        let (namespace, classes, method) = match <[Vec<String>; 3]>::try_from(positional) {
+            Ok([mut namespace, classes, mut method]) => {
+                (namespace.remove(0), classes, method.remove(0))
+            }
+            Err(_) => return Err(Error::new_spanned(args, "Expected 3 arguments")),
+        };
+
         Ok(Self {
             namespace,
-            class,
+            classes,
             method,
+            priority,
+            signature,
             input,
         })
     }
@@ -138,6 +317,20 @@ impl Metadata {
             ));
         }
 
+        if let Some(signature) = &self.signature {
+            let count = self.params().count();
+            if signature.params.len() != count {
+                return Err(Error::new_spanned(
+                    &signature.expr,
+                    format!(
+                        "Signature has {} parameters but the function takes {}",
+                        signature.params.len(),
+                        count
+                    ),
+                ));
+            }
+        }
+
         Ok(())
     }
 
@@ -157,6 +350,31 @@ impl Metadata {
         format_ident!("_{}_fn", fn_name)
     }
 
+    fn priority(&self) -> TokenStream2 {
+        match &self.priority {
+            Some(priority) => quote_spanned!(priority.span()=> #priority),
+            None => quote!(0),
+        }
+    }
+
+    /// Whether the hook targets instantiations of generic classes or methods,
+    /// whose code can be shared with other instantiations
+    fn is_generic(&self) -> bool {
+        self.classes.len() > 1
+            || self
+                .classes
+                .iter()
+                .chain([&self.method])
+                .any(|name| has_generics(name))
+    }
+
+    /// Thread local holding the `MethodInfo` the hook is currently called for
+    fn current_method(&self) -> Ident {
+        let hook_name = self.hook_name().to_string();
+        let fn_name = hook_name.to_snake_case();
+        format_ident!("_{}_method", fn_name)
+    }
+
     fn filtered_attrs(&self) -> impl Iterator<Item = &'_ Attribute> + '_ {
         self.input.attrs.iter().filter(|a| !attr_is(a, "hook"))
     }
@@ -283,11 +501,48 @@ impl Metadata {
             .params_ident()
             .map(|i| quote!(::quest_hook::libil2cpp::Parameter::from_actual(#i),));
 
+        if !self.is_generic() {
+            return quote! {
+                #[inline(never)]
+                pub #unsafety extern "C" fn #name(#this_param #(#params_params)*) -> #return_ty {
+                    #inner_fn
+                    let r = inner(#this_arg #(#params_args)*);
+                    ::quest_hook::libil2cpp::Return::into_actual(r)
+                }
+            };
+        }
+
+        let hook_name = self.hook_name();
+        let current_method = self.current_method();
+        let original_ty = self.original_ty();
+
+        let this_passed = self.this_ident().map(|i| quote!(#i,));
+        let params_passed = self.params_ident().map(|i| quote!(#i,));
+
+        // il2cpp passes the `MethodInfo` of the called method as an extra last
+        // argument, which tells apart instantiations sharing the same code
         quote! {
             #[inline(never)]
-            pub #unsafety extern "C" fn #name(#this_param #(#params_params)*) -> #return_ty {
+            pub #unsafety extern "C" fn #name(
+                #this_param
+                #(#params_params)*
+                method: *const ::quest_hook::libil2cpp::MethodInfo,
+            ) -> #return_ty {
+                use ::std::mem::transmute;
+                use ::std::sync::atomic::Ordering;
+
                 #inner_fn
+
+                if !#hook_name.hooked_methods().contains(&(method as usize)) {
+                    let ptr = #hook_name.next.load(Ordering::SeqCst);
+                    assert!(!ptr.is_null(), "hook is not installed");
+                    let original = unsafe { transmute::<*mut (), #original_ty>(ptr) };
+                    return original(#this_passed #(#params_passed)* method);
+                }
+
+                let previous = #current_method.with(|m| m.replace(method));
                 let r = inner(#this_arg #(#params_args)*);
+                #current_method.with(|m| m.set(previous));
                 ::quest_hook::libil2cpp::Return::into_actual(r)
             }
         }
@@ -297,9 +552,15 @@ impl Metadata {
         let vis = &self.input.vis;
         let struct_name = self.struct_name();
 
+        let methods = self
+            .is_generic()
+            .then(|| quote!(methods: ::std::sync::atomic::AtomicPtr<::std::boxed::Box<[usize]>>,));
+
         quote! {
             #vis struct #struct_name {
-                hook: ::quest_hook::inline_hook::Hook,
+                target: ::std::sync::atomic::AtomicPtr<()>,
+                next: ::std::sync::atomic::AtomicPtr<()>,
+                #methods
             }
         }
     }
@@ -309,11 +570,31 @@ impl Metadata {
         let name = self.hook_name();
         let struct_name = self.struct_name();
 
+        if !self.is_generic() {
+            return quote! {
+                #[allow(non_upper_case_globals)]
+                #vis static #name: #struct_name = #struct_name {
+                    target: ::std::sync::atomic::AtomicPtr::new(::std::ptr::null_mut()),
+                    next: ::std::sync::atomic::AtomicPtr::new(::std::ptr::null_mut()),
+                };
+            };
+        }
+
+        let current_method = self.current_method();
+
         quote! {
             #[allow(non_upper_case_globals)]
             #vis static #name: #struct_name = #struct_name {
-                hook: ::quest_hook::inline_hook::Hook::new(),
+                target: ::std::sync::atomic::AtomicPtr::new(::std::ptr::null_mut()),
+                next: ::std::sync::atomic::AtomicPtr::new(::std::ptr::null_mut()),
+                methods: ::std::sync::atomic::AtomicPtr::new(::std::ptr::null_mut()),
             };
+
+            ::std::thread_local! {
+                #[allow(non_upper_case_globals)]
+                static #current_method: ::std::cell::Cell<*const ::quest_hook::libil2cpp::MethodInfo> =
+                    const { ::std::cell::Cell::new(::std::ptr::null()) };
+            }
         }
     }
 
@@ -321,43 +602,146 @@ impl Metadata {
         let vis = &self.input.vis;
 
         let namespace = &self.namespace;
-        let class = &self.class;
         let method = &self.method;
 
         let this_ty = self.typechecking_this_ty();
         let params_ty = self.typechecking_params_ty();
         let return_ty = self.return_ty();
 
+        let find_method = match &self.signature {
+            Some(signature) => {
+                let params_names = &signature.params;
+                let return_ty_name = match &signature.return_ty {
+                    Some(r) => quote!(Some(#r)),
+                    None => quote!(None),
+                };
+                quote! {
+                    class.find_method_callee_exact::<#this_ty, #params_ty, #return_ty>(
+                        #method,
+                        &[#(#params_names),*],
+                        #return_ty_name,
+                    )
+                }
+            }
+            None => quote! {
+                class.find_method_callee::<#this_ty, #params_ty, #return_ty>(#method)
+            },
+        };
+
+        let hook_name = self.hook_name();
         let fn_name = self.fn_name();
+        let priority = self.priority();
+
+        if !self.is_generic() {
+            let class = &self.classes[0];
+
+            return quote! {
+                #vis fn install(&self) -> Result<(), quest_hook::HookInstallError> {
+                    use ::std::sync::atomic::Ordering;
+                    use ::quest_hook::HookInstallError;
+                    use ::quest_hook::libil2cpp::{Il2CppClass, WrapRaw};
+
+                    if !self.target.load(Ordering::SeqCst).is_null() {
+                        return Err(HookInstallError::AlreadyInstalled);
+                    }
+
+                    let class = match Il2CppClass::find(#namespace, #class) {
+                        Some(class) => class,
+                        None => return Err(HookInstallError::ClassNotFound),
+                    };
+                    let method = match #find_method {
+                        Ok(method) => method,
+                        Err(_) => return Err(HookInstallError::MethodNotFound),
+                    };
+
+                    let target = method.raw().methodPointer.unwrap() as *mut ();
+                    unsafe { ::quest_hook::chain::install(target, #fn_name as *const (), &#hook_name.next, #priority) }?;
+                    self.target.store(target, Ordering::SeqCst);
+                    Ok(())
+                }
+            };
+        }
+
+        let classes = &self.classes;
 
         quote! {
             #vis fn install(&self) -> Result<(), quest_hook::HookInstallError> {
-                use ::std::ptr::null_mut;
                 use ::std::sync::atomic::Ordering;
                 use ::quest_hook::HookInstallError;
-                use ::quest_hook::libil2cpp::{Il2CppClass, WrapRaw};
+                use ::quest_hook::libil2cpp::{Il2CppClass, MethodInfo, WrapRaw};
 
-                if self.hook.is_installed() {
+                if !self.target.load(Ordering::SeqCst).is_null() {
                     return Err(HookInstallError::AlreadyInstalled);
                 }
 
-                let class = match Il2CppClass::find(#namespace, #class) {
-                    Some(class) => class,
-                    None => return Err(HookInstallError::ClassNotFound),
-                };
-                let method = match class.find_method_callee::<#this_ty, #params_ty, #return_ty>(#method) {
-                    Ok(method) => method,
-                    Err(_) => return Err(HookInstallError::MethodNotFound),
-                };
+                let mut methods = ::std::vec::Vec::new();
+                for class in [#(#classes),*] {
+                    let class = match Il2CppClass::find_instantiation(#namespace, class) {
+                        Some(class) => class,
+                        None => return Err(HookInstallError::ClassNotFound),
+                    };
+                    let method = match #find_method {
+                        Ok(method) => method,
+                        Err(_) => return Err(HookInstallError::MethodNotFound),
+                    };
+                    methods.push(method);
+                }
 
-                let success = unsafe {
-                    self.hook.install(method.raw().methodPointer.unwrap() as *const (), #fn_name as *const ())
-                };
-                if success {
-                    Ok(())
-                } else {
-                    Err(HookInstallError::InstallError)
+                let target = methods[0].raw().methodPointer.unwrap() as *mut ();
+                if methods
+                    .iter()
+                    .any(|m| m.raw().methodPointer.unwrap() as *mut () != target)
+                {
+                    return Err(HookInstallError::UnsharedInstantiations);
                 }
+
+                // The list is published before the hook can be called and
+                // never changes afterwards, so the hook reads it without locking
+                let methods: ::std::boxed::Box<[usize]> = methods
+                    .into_iter()
+                    .map(|m| m as *const MethodInfo as usize)
+                    .collect();
+                let methods = ::std::boxed::Box::into_raw(::std::boxed::Box::new(methods));
+                self.methods.store(methods, Ordering::SeqCst);
+                if let Err(e) =
+                    unsafe { ::quest_hook::chain::install(target, #fn_name as *const (), &#hook_name.next, #priority) }
+                {
+                    // The hook was never called, so nothing can be reading the list
+                    self.methods.store(::std::ptr::null_mut(), Ordering::SeqCst);
+                    drop(unsafe { ::std::boxed::Box::from_raw(methods) });
+                    return Err(e);
+                }
+                self.target.store(target, Ordering::SeqCst);
+                Ok(())
+            }
+        }
+    }
+
+    fn uninstall_fn(&self) -> TokenStream2 {
+        let vis = &self.input.vis;
+        let fn_name = self.fn_name();
+
+        let clear_methods = self.is_generic().then(|| {
+            // A call of the hook which started before uninstalling could
+            // still be reading the list, so it is leaked instead of freed
+            quote!(self.methods.store(null_mut(), Ordering::SeqCst);)
+        });
+
+        quote! {
+            #vis fn uninstall(&self) -> Result<(), quest_hook::HookUninstallError> {
+                use ::std::ptr::null_mut;
+                use ::std::sync::atomic::Ordering;
+                use ::quest_hook::HookUninstallError;
+
+                let target = self.target.load(Ordering::SeqCst);
+                if target.is_null() {
+                    return Err(HookUninstallError::NotInstalled);
+                }
+
+                unsafe { ::quest_hook::chain::uninstall(target, #fn_name as *const ()) }?;
+                self.target.store(null_mut(), Ordering::SeqCst);
+                #clear_methods
+                Ok(())
             }
         }
     }
@@ -366,8 +750,11 @@ impl Metadata {
         let this_ty = self.actual_this_ty().map(|t| quote!(#t,));
         let params_ty = self.actual_params_ty().map(|t| quote!(#t,));
         let return_ty = self.actual_return_ty();
+        let method_ty = self
+            .is_generic()
+            .then(|| quote!(*const ::quest_hook::libil2cpp::MethodInfo,));
 
-        quote!(extern "C" fn(#this_ty #(#params_ty)*) -> #return_ty)
+        quote!(extern "C" fn(#this_ty #(#params_ty)* #method_ty) -> #return_ty)
     }
 
     fn original_fn(&self) -> TokenStream2 {
@@ -393,15 +780,30 @@ impl Metadata {
             .params_ident()
             .map(|i| quote!(::quest_hook::libil2cpp::Parameter::into_actual(#i),));
 
+        // Outside of the hook, the first hooked instantiation is used
+        let method_arg = self.is_generic().then(|| {
+            let current_method = self.current_method();
+            quote! {
+                match #current_method.with(::std::cell::Cell::get) {
+                    method if method.is_null() => {
+                        self.hooked_methods().first().copied().unwrap_or_default()
+                            as *const ::quest_hook::libil2cpp::MethodInfo
+                    }
+                    method => method,
+                },
+            }
+        });
+
         quote! {
             #vis fn original(&self, #this_param #(#params_params)*) -> #return_ty {
                 use ::std::mem::transmute;
                 use ::std::sync::atomic::Ordering;
 
-                let ptr = self.hook.original().expect("hook is not installed");
-                let original = unsafe { transmute::<*const (), #original_ty>(ptr) };
+                let ptr = self.next.load(Ordering::SeqCst);
+                assert!(!ptr.is_null(), "hook is not installed");
+                let original = unsafe { transmute::<*mut (), #original_ty>(ptr) };
 
-                let r = original(#this_arg #(#params_args)*);
+                let r = original(#this_arg #(#params_args)* #method_arg);
                 ::quest_hook::libil2cpp::Return::from_actual(r)
             }
         }
@@ -410,12 +812,28 @@ impl Metadata {
     fn struct_impl(&self) -> TokenStream2 {
         let struct_name = self.struct_name();
         let install_fn = self.install_fn();
+        let uninstall_fn = self.uninstall_fn();
         let original_fn = self.original_fn();
 
+        let hooked_methods_fn = self.is_generic().then(|| {
+            quote! {
+                /// `MethodInfo`s of the hooked instantiations, empty if not installed
+                fn hooked_methods(&self) -> &'static [usize] {
+                    let methods = self.methods.load(::std::sync::atomic::Ordering::SeqCst);
+                    match unsafe { methods.as_ref() } {
+                        Some(methods) => methods,
+                        None => &[],
+                    }
+                }
+            }
+        });
+
         quote! {
             impl #struct_name {
                 #install_fn
+                #uninstall_fn
                 #original_fn
+                #hooked_methods_fn
             }
         }
     }
@@ -424,7 +842,8 @@ impl Metadata {
         let struct_name = self.struct_name();
 
         let namespace = &self.namespace;
-        let class = &self.class;
+        let class = &self.classes[0];
+        let classes = &self.classes;
         let method = &self.method;
 
         let this_ty = staticify(self.typechecking_this_ty());
@@ -432,6 +851,7 @@ impl Metadata {
         let return_ty = staticify(self.return_ty());
 
         let fn_name = self.fn_name();
+        let priority = self.priority();
 
         quote! {
             impl ::quest_hook::Hook for #struct_name {
@@ -441,14 +861,22 @@ impl Metadata {
 
                 const NAMESPACE: &'static str = #namespace;
                 const CLASS_NAME: &'static str = #class;
+                const CLASS_NAMES: &'static [&'static str] = &[#(#classes),*];
                 const METHOD_NAME: &'static str = #method;
+                const PRIORITY: i32 = #priority;
 
                 fn install(&self) -> Result<(), ::quest_hook::HookInstallError> {
                     self.install()
                 }
+                fn uninstall(&self) -> Result<(), ::quest_hook::HookUninstallError> {
+                    self.uninstall()
+                }
 
                 fn original(&self) -> Option<*const ()> {
-                    self.hook.original()
+                    match self.next.load(::std::sync::atomic::Ordering::SeqCst) {
+                        next if next.is_null() => None,
+                        next => Some(next as *const ()),
+                    }
                 }
                 fn hook(&self) -> *const () {
                     #fn_name as *const ()
@@ -458,6 +886,12 @@ impl Metadata {
     }
 }
 
+/// Whether a class or method name includes generic arguments, unlike the
+/// names of compiler generated types and methods such as `<Start>b__0`
+fn has_generics(name: &str) -> bool {
+    name.ends_with('>') && !name.starts_with('<')
+}
+
 fn unit_ty() -> Type {
     Type::Tuple(TypeTuple {
         paren_token: Default::default(),
@@ -483,8 +917,84 @@ fn staticify(tokens: impl ToTokens) -> TokenStream2 {
                 Some(TokenTree2::Punct(p)) if p.as_char() == '\'' => ts.extend_one(tt),
                 _ => ts.extend_one(quote_spanned!(tt.span()=> &'static)),
             },
+            // Lifetimes such as the `'_` in `Ref<'_, T>`
+            TokenTree2::Punct(p) if p.as_char() == '\'' => {
+                iter.next();
+                ts.extend(quote_spanned!(tt.span()=> 'static));
+            }
             _ => ts.extend_one(tt),
         }
     }
     ts
 }
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_str;
+
+    use super::{has_generics, split_type_list, Signature};
+
+    fn signature(s: &str) -> Option<(Option<String>, Vec<String>)> {
+        let expr = parse_str(&format!("{s:?}")).unwrap();
+        Signature::from_signature(&expr)
+            .ok()
+            .map(|s| (s.return_ty, s.params))
+    }
+
+    #[test]
+    fn type_list() {
+        assert_eq!(split_type_list(""), Some(vec![]));
+        assert_eq!(split_type_list("  "), Some(vec![]));
+        assert_eq!(
+            split_type_list("int, System.String"),
+            Some(vec!["int".into(), "System.String".into()])
+        );
+        assert_eq!(
+            split_type_list("Dictionary<int, string>, float[,], bool&"),
+            Some(vec![
+                "Dictionary<int, string>".into(),
+                "float[,]".into(),
+                "bool&".into(),
+            ])
+        );
+
+        assert_eq!(split_type_list("int,"), None);
+        assert_eq!(split_type_list(", int"), None);
+        assert_eq!(split_type_list("List<int>>"), None);
+    }
+
+    #[test]
+    fn generic_names() {
+        assert!(has_generics("List`1<int>"));
+        assert!(has_generics("Dictionary`2<string, List`1<int>>"));
+        assert!(has_generics("Method<T>"));
+
+        assert!(!has_generics("List`1"));
+        assert!(!has_generics("<Start>b__0"));
+        assert!(!has_generics("<>c__DisplayClass0_0"));
+    }
+
+    #[test]
+    fn signature_parser() {
+        assert_eq!(
+            signature("void (int, System.String)"),
+            Some((
+                Some("void".into()),
+                vec!["int".into(), "System.String".into()]
+            ))
+        );
+        assert_eq!(
+            signature("List<int>(float)"),
+            Some((Some("List<int>".into()), vec!["float".into()]))
+        );
+        assert_eq!(signature("(int&)"), Some((None, vec!["int&".into()])));
+        assert_eq!(signature("void ()"), Some((Some("void".into()), vec![])));
+
+        assert_eq!(signature("void"), None);
+        assert_eq!(signature("void (int"), None);
+        assert_eq!(signature("void (int,)"), None);
+
+        let expr = parse_str("[\"int\"]").unwrap();
+        assert!(Signature::from_signature(&expr).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::parse::{Parse, ParseStream};
-use syn::{Error, FnArg, ForeignItemFn, LitByteStr, PatType, Signature};
+use syn::{Error, FnArg, ForeignItemFn, LitByteStr, LitStr, PatType, ReturnType, Signature};
 
 pub struct Input(Vec<ForeignItemFn>);
 
@@ -16,10 +16,10 @@ impl Parse for Input {
 }
 
 pub fn expand(input: &Input) -> Result<TokenStream, Error> {
-    let mut ts = quote! {
-        static LIBIL2CPP: LazyLock<Library> =
-            LazyLock::new(|| unsafe { Library::new("libil2cpp.so") }.unwrap());
-    };
+    let mut fields = Vec::new();
+    let mut loads = Vec::new();
+    let mut inits = Vec::new();
+    let mut wrappers = Vec::new();
 
     for ForeignItemFn {
         attrs,
@@ -34,7 +34,9 @@ pub fn expand(input: &Input) -> Result<TokenStream, Error> {
         ..
     } in input.0.iter()
     {
-        let name = LitByteStr::new(format!("il2cpp_{}", ident).as_bytes(), ident.span());
+        let symbol = format!("il2cpp_{}", ident);
+        let name = LitByteStr::new(symbol.as_bytes(), ident.span());
+        let symbol = LitStr::new(&symbol, ident.span());
 
         let inputs = inputs
             .iter()
@@ -50,17 +52,73 @@ pub fn expand(input: &Input) -> Result<TokenStream, Error> {
         let input_pats = inputs.iter().map(|i| &i.pat);
         let input_tys = inputs.iter().map(|i| &i.ty);
 
-        let wrapper = quote! {
-            #(#attrs) *
-            #vis unsafe fn #ident(#(#inputs),*) #output {
-                static FN: OnceLock<Symbol<'static, unsafe extern "C" fn(#(#input_tys),*) #output>> =
-                    OnceLock::new();
-                let fun = FN.get_or_init(|| unsafe { LIBIL2CPP.get(#name) }.unwrap());
-                (**fun)(#(#input_pats),*)
-            }
-        };
-        ts.extend_one(wrapper);
+        // Functions which runtimes may lack are marked `#[optional]`, and
+        // return `None` when they are missing instead of failing to load
+        let optional = attrs.iter().any(|a| a.path.is_ident("optional"));
+        let attrs = attrs.iter().filter(|a| !a.path.is_ident("optional"));
+
+        let ty = quote!(unsafe extern "C" fn(#(#input_tys),*) #output);
+        if optional {
+            let output = match output {
+                ReturnType::Default => quote!(-> Option<()>),
+                ReturnType::Type(_, ty) => quote!(-> Option<#ty>),
+            };
+            fields.push(quote!(pub(crate) #ident: Option<#ty>));
+            loads.push(quote! {
+                let #ident = unsafe { library.get::<#ty>(#name) }.ok().map(|symbol| *symbol);
+            });
+            inits.push(quote!(#ident));
+            wrappers.push(quote! {
+                #(#attrs) *
+                #vis unsafe fn #ident(#(#inputs),*) #output {
+                    functions().#ident.map(|f| f(#(#input_pats),*))
+                }
+            });
+        } else {
+            fields.push(quote!(#ident: #ty));
+            loads.push(quote! {
+                let #ident = match unsafe { library.get::<#ty>(#name) } {
+                    Ok(symbol) => Some(*symbol),
+                    Err(_) => {
+                        missing.push(#symbol);
+                        None
+                    }
+                };
+            });
+            inits.push(quote!(#ident: #ident.unwrap()));
+            wrappers.push(quote! {
+                #(#attrs) *
+                #vis unsafe fn #ident(#(#inputs),*) #output {
+                    (functions().#ident)(#(#input_pats),*)
+                }
+            });
+        }
     }
 
+    let ts = quote! {
+        /// Table of il2cpp functions resolved from a loaded module, which
+        /// needs to stay loaded for as long as they are called
+        pub(crate) struct Functions {
+            #(#fields,)*
+        }
+
+        impl Functions {
+            /// Resolves every il2cpp function from the module, listing the
+            /// missing exports on failure
+            pub(crate) fn load(library: &Library) -> Result<Self, LoadError> {
+                let mut missing = Vec::new();
+                #(#loads)*
+                if !missing.is_empty() {
+                    return Err(LoadError::MissingExports(missing));
+                }
+                Ok(Self {
+                    #(#inits,)*
+                })
+            }
+        }
+
+        #(#wrappers)*
+    };
+
     Ok(ts.into())
 }
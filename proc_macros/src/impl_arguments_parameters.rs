@@ -12,13 +12,34 @@ pub fn expand(range: Range<usize>) -> Result<TokenStream> {
             .clone()
             .enumerate()
             .map(|(n, gp)| quote!(<#gp>::matches(params.get_unchecked(#n).ty())));
-        let invokables = (0..n).map(Index::from).map(|n| quote!(self.#n.invokable()));
+        let names_argument = generic_params_argument
+            .clone()
+            .map(|gp| quote!(<#gp>::type_name()));
+        let resolves_argument = generic_params_argument
+            .clone()
+            .map(|gp| quote!(<#gp>::resolve()?;));
+        // Each argument is staged in a closure nested in the previous one, so
+        // they all live until `f` returns
+        let invokable_args = (0..n).map(|n| format_ident!("a{}", n));
+        let invokables = (0..n)
+            .rev()
+            .fold(quote!(f([#(#invokable_args),*])), |body, n| {
+                let index = Index::from(n);
+                let arg = format_ident!("a{}", n);
+                quote!(Argument::with_invokable(&mut self.#index, |#arg| #body))
+            });
 
         let generic_params_parameter = (1..=n).map(|n| format_ident!("P{}", n));
         let matches_parameter = generic_params_parameter
             .clone()
             .enumerate()
             .map(|(n, gp)| quote!(<#gp>::matches(params.get_unchecked(#n).ty())));
+        let names_parameter = generic_params_parameter
+            .clone()
+            .map(|gp| quote!(<#gp>::type_name()));
+        let resolves_parameter = generic_params_parameter
+            .clone()
+            .map(|gp| quote!(<#gp>::resolve()?;));
 
         let generic_params_argument_tuple = generic_params_argument.clone();
         let generic_params_argument_where = generic_params_argument.clone();
@@ -34,13 +55,22 @@ pub fn expand(range: Range<usize>) -> Result<TokenStream> {
             {
                 type Type = (#(#generic_params_argument_type::Type,)*);
 
+                fn resolve() -> Result<(), Il2CppError<'static>> {
+                    #(#resolves_argument)*
+                    Ok(())
+                }
+
                 fn matches(method: &MethodInfo) -> bool {
                     let params = method.parameters();
                     params.len() == #n && unsafe { #(#matches_argument) && * }
                 }
 
-                fn invokable(&mut self) -> [*mut c_void; #n] {
-                    [#(#invokables),*]
+                fn type_name() -> String {
+                    format!("({})", [#(#names_argument),*].join(", "))
+                }
+
+                fn with_invokable<O>(&mut self, f: impl FnOnce([*mut c_void; #n]) -> O) -> O {
+                    #invokables
                 }
             }
 
@@ -50,10 +80,19 @@ pub fn expand(range: Range<usize>) -> Result<TokenStream> {
             {
                 const COUNT: usize = #n;
 
+                fn resolve() -> Result<(), Il2CppError<'static>> {
+                    #(#resolves_parameter)*
+                    Ok(())
+                }
+
                 fn matches(method: &MethodInfo) -> bool {
                     let params = method.parameters();
                     params.len() == #n && unsafe { #(#matches_parameter) && * }
                 }
+
+                fn type_name() -> String {
+                    format!("({})", [#(#names_parameter),*].join(", "))
+                }
             }
         };
         ts.extend(TokenStream::from(impl_ts));
@@ -0,0 +1,100 @@
+use std::ops::Range;
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::Result;
+
+pub fn expand(range: Range<usize>) -> Result<TokenStream> {
+    let mut ts = TokenStream::new();
+    for n in range {
+        let generic_params = (1..=n).map(|n| format_ident!("P{}", n));
+        let params = (1..=n).map(|n| format_ident!("p{}", n));
+
+        let generic_params_impl = generic_params.clone();
+        let generic_params_tuple = generic_params.clone();
+        let generic_params_fn = generic_params.clone();
+        let generic_params_where = generic_params.clone();
+        let generic_params_inner = generic_params.clone();
+        let generic_params_inner_fn = generic_params.clone();
+        let generic_params_inner_where = generic_params.clone();
+        let generic_params_call = generic_params.clone();
+        let params_decl = params
+            .clone()
+            .zip(generic_params.clone())
+            .map(|(p, gp)| quote!(#p: <#gp as Parameter>::Actual));
+        let params_call = params
+            .clone()
+            .zip(generic_params.clone())
+            .map(|(p, gp)| quote!(<#gp as Parameter>::from_actual(#p)));
+
+        let generic_params_fn_ptr = generic_params.clone();
+        let generic_params_fn_ptr_ty = generic_params.clone();
+        let generic_params_fn_ptr_where = generic_params.clone();
+        let generic_params_fn_ptr_inner = generic_params.clone();
+        let generic_params_fn_ptr_inner_ty = generic_params.clone();
+        let generic_params_fn_ptr_inner_where = generic_params.clone();
+        let generic_params_fn_ptr_tuple = generic_params.clone();
+        let generic_params_fn_ptr_call = generic_params.clone();
+        let params_fn_ptr_decl = params
+            .clone()
+            .zip(generic_params.clone())
+            .map(|(p, gp)| quote!(#p: <#gp as Parameter>::Actual));
+        let params_fn_ptr_call = params
+            .zip(generic_params.clone())
+            .map(|(p, gp)| quote!(<#gp as Parameter>::from_actual(#p)));
+
+        let impl_ts = quote! {
+            unsafe impl<F, R, #(#generic_params_impl),*> Callback<(#(#generic_params_tuple,)*), R> for F
+            where
+                F: Fn(#(#generic_params_fn),*) -> R + Send + Sync + 'static,
+                R: Return,
+                #(#generic_params_where: Parameter),*
+            {
+                fn trampoline() -> *const () {
+                    unsafe extern "C" fn trampoline<F, R, #(#generic_params_inner),*>(
+                        _: *mut c_void,
+                        #(#params_decl,)*
+                        method: *const raw::MethodInfo,
+                    ) -> R::Actual
+                    where
+                        F: Fn(#(#generic_params_inner_fn),*) -> R + Send + Sync + 'static,
+                        R: Return,
+                        #(#generic_params_inner_where: Parameter),*
+                    {
+                        let callback = CallbackMethod::<F>::callback(method);
+                        callback(#(#params_call),*).into_actual()
+                    }
+
+                    trampoline::<F, R, #(#generic_params_call),*> as *const ()
+                }
+            }
+
+            unsafe impl<R, #(#generic_params_fn_ptr),*> Callback<(#(#generic_params_fn_ptr_tuple,)*), R>
+                for extern "C" fn(#(#generic_params_fn_ptr_ty),*) -> R
+            where
+                R: Return + 'static,
+                #(#generic_params_fn_ptr_where: Parameter + 'static),*
+            {
+                fn trampoline() -> *const () {
+                    unsafe extern "C" fn trampoline<R, #(#generic_params_fn_ptr_inner),*>(
+                        _: *mut c_void,
+                        #(#params_fn_ptr_decl,)*
+                        method: *const raw::MethodInfo,
+                    ) -> R::Actual
+                    where
+                        R: Return + 'static,
+                        #(#generic_params_fn_ptr_inner_where: Parameter + 'static),*
+                    {
+                        let callback = CallbackMethod::<extern "C" fn(#(#generic_params_fn_ptr_inner_ty),*) -> R>::callback(method);
+                        callback(#(#params_fn_ptr_call),*).into_actual()
+                    }
+
+                    trampoline::<R, #(#generic_params_fn_ptr_call),*> as *const ()
+                }
+            }
+        };
+        ts.extend(TokenStream::from(impl_ts));
+    }
+
+    Ok(ts)
+}
@@ -17,6 +17,8 @@ pub fn expand(range: Range<usize>) -> Result<TokenStream> {
                 .write_unaligned(#g::class().ty().reflection_object());
             });
 
+        let resolves = generics.clone().map(|g| quote!(#g::try_class()?;));
+
         let generics_impl = generics.clone();
         let generics_ty = generics.clone();
         let generics_where = generics.clone();
@@ -28,6 +30,11 @@ pub fn expand(range: Range<usize>) -> Result<TokenStream> {
             {
                 const COUNT: usize = #n;
 
+                fn resolve() -> Result<(), Il2CppError<'static>> {
+                    #(#resolves)*
+                    Ok(())
+                }
+
                 fn type_array() -> &'static mut raw::Il2CppArray {
                     let arr = unsafe { raw::array_new(Il2CppReflectionType::class().raw(), #n) }.unwrap();
                     unsafe {
@@ -5,8 +5,8 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::token::Brace;
 use syn::{
-    AngleBracketedGenericArguments, Block, Error, GenericArgument, Ident, LitStr, Path,
-    PathArguments, Result, Token, Type, TypeGroup, TypeParen, TypePath,
+    AngleBracketedGenericArguments, Block, Error, Fields, GenericArgument, Ident, ItemEnum, LitStr,
+    Path, PathArguments, Result, Token, Type, TypeGroup, TypeParen, TypePath,
 };
 
 pub struct Input {
@@ -34,14 +34,7 @@ impl Parse for Input {
 
         input.parse::<Token![=>]>()?;
 
-        let namespace_and_class = if input.peek(Ident) {
-            NamespaceAndClass::Ident(Punctuated::parse_separated_nonempty(input)?)
-        } else {
-            let namespace = input.parse()?;
-            input.parse::<Token![.]>()?;
-            let class = input.parse()?;
-            NamespaceAndClass::Literal { namespace, class }
-        };
+        let namespace_and_class: NamespaceAndClass = input.parse()?;
 
         let generics: Option<AngleBracketedGenericArguments> = if input.peek(Token![<]) {
             Some(input.parse()?)
@@ -75,8 +68,39 @@ impl Parse for Input {
             return Err(Error::new_spanned(g, "mismatched Rust and C# generics"));
         }
 
-        let (namespace, class) = match namespace_and_class {
-            NamespaceAndClass::Ident(punctuated) => {
+        let (namespace, class) = namespace_and_class.into_names(cs_generics.len())?;
+
+        Ok(Self {
+            path,
+            ty,
+            namespace,
+            class,
+            rust_generics,
+            cs_generics,
+            class_getter,
+        })
+    }
+}
+
+impl Parse for NamespaceAndClass {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        if input.peek(Ident) {
+            Ok(Self::Ident(Punctuated::parse_separated_nonempty(input)?))
+        } else {
+            let namespace = input.parse()?;
+            input.parse::<Token![.]>()?;
+            let class = input.parse()?;
+            Ok(Self::Literal { namespace, class })
+        }
+    }
+}
+
+impl NamespaceAndClass {
+    /// Namespace and class name, with the number of generic parameters
+    /// appended to the latter unless it was provided as a literal
+    fn into_names(self, generics: usize) -> Result<(String, String)> {
+        match self {
+            Self::Ident(punctuated) => {
                 let namespace_and_class: Vec<Ident> = punctuated.into_iter().collect();
                 let (class, namespace) = namespace_and_class
                     .split_last()
@@ -86,23 +110,67 @@ impl Parse for Input {
                     .map(Ident::to_string)
                     .collect::<Vec<_>>()
                     .join(".");
-                let class = match cs_generics.len() {
+                let class = match generics {
                     0 => class.to_string(),
                     n => format!("{}`{}", class, n),
                 };
-                (namespace, class)
+                Ok((namespace, class))
             }
-            NamespaceAndClass::Literal { namespace, class } => (namespace.value(), class.value()),
-        };
+            Self::Literal { namespace, class } => Ok((namespace.value(), class.value())),
+        }
+    }
+}
+
+pub struct EnumInput {
+    path: Path,
+    namespace: String,
+    class: String,
+    item: ItemEnum,
+    repr: Ident,
+}
+
+/// Integer types C# enums can have as their underlying type
+const ENUM_REPRS: &[&str] = &["i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64"];
+
+impl Parse for EnumInput {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        input.parse::<Token![in]>()?;
+        let path = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let (namespace, class) = input.parse::<NamespaceAndClass>()?.into_names(0)?;
+        let item: ItemEnum = input.parse()?;
+
+        if !item.generics.params.is_empty() {
+            return Err(Error::new_spanned(
+                &item.generics,
+                "C# enums can't be generic",
+            ));
+        }
+        if let Some(variant) = item.variants.iter().find(|v| v.fields != Fields::Unit) {
+            return Err(Error::new_spanned(
+                &variant.fields,
+                "C# enums can't have variants with fields",
+            ));
+        }
+
+        let mut repr = None;
+        for attr in item.attrs.iter().filter(|a| a.path.is_ident("repr")) {
+            let args = attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+            repr = args.into_iter().find(|a| ENUM_REPRS.iter().any(|r| a == r));
+        }
+        let repr = repr.ok_or_else(|| {
+            Error::new_spanned(
+                &item.ident,
+                "C# enums need an integer representation such as #[repr(i32)]",
+            )
+        })?;
 
         Ok(Self {
             path,
-            ty,
             namespace,
             class,
-            rust_generics,
-            cs_generics,
-            class_getter,
+            item,
+            repr,
         })
     }
 }
@@ -151,6 +219,112 @@ pub fn expand(input: &Input, semantics: Semantics) -> TokenStream {
     })
 }
 
+pub fn expand_enum(input: &EnumInput) -> TokenStream {
+    let path = &input.path;
+    let item = &input.item;
+    let ident = &item.ident;
+    let vis = &item.vis;
+    let repr = &input.repr;
+    let namespace = &input.namespace;
+    let class = &input.class;
+    let variants: Vec<&Ident> = item.variants.iter().map(|v| &v.ident).collect();
+
+    // C# enums can hold any value of their underlying type, so the enum becomes
+    // a newtype around it with a constant for each variant
+    let attrs = item.attrs.iter().filter(|a| !a.path.is_ident("repr"));
+    let variant_attrs = item.variants.iter().map(|v| &v.attrs);
+    let mut discriminant = quote!(0);
+    let mut values = Vec::new();
+    for variant in &item.variants {
+        if let Some((_, expr)) = &variant.discriminant {
+            discriminant = quote!(#expr);
+        }
+        values.push(discriminant.clone());
+        discriminant = quote!((#discriminant) + 1);
+    }
+
+    // The class is checked to be an enum with the right underlying type and
+    // values once, before any of the variants can reach C#
+    let class_getter: Block = syn::parse_quote!({
+        static CLASS: ::std::sync::OnceLock<&'static #path::Il2CppClass> =
+            ::std::sync::OnceLock::new();
+        if let ::std::option::Option::Some(class) = CLASS.get() {
+            return ::std::result::Result::Ok(class);
+        }
+
+        let class = #path::Il2CppClass::find(#namespace, #class).ok_or_else(|| {
+            #path::Il2CppError::ClassNotFound {
+                namespace: #namespace.to_owned(),
+                name: #class.to_owned(),
+            }
+        })?;
+        let underlying = <#repr as #path::Type>::try_class()?;
+        if !class.is_enum() || !underlying.is_assignable_from(class.element_class()) {
+            return ::std::result::Result::Err(#path::Il2CppError::TypeMismatch {
+                expected: ::std::format!("enum with underlying type {}", underlying),
+                actual: class.to_string(),
+            });
+        }
+
+        let values: ::std::vec::Vec<#repr> = class
+            .fields()
+            .iter()
+            .filter(|f| f.is_literal())
+            .map(|f| unsafe { f.load_static_unchecked::<#repr>() })
+            .collect();
+        #(
+            if !values.contains(&Self::#variants.0) {
+                return ::std::result::Result::Err(#path::Il2CppError::VariantNotFound {
+                    class: class.to_string(),
+                    name: ::std::stringify!(#variants).to_owned(),
+                    value: Self::#variants.0.to_string(),
+                });
+            }
+        )*
+
+        CLASS.get_or_init(|| class)
+    });
+
+    let type_input = Input {
+        path: path.clone(),
+        ty: syn::parse_quote!(#ident),
+        namespace: input.namespace.clone(),
+        class: input.class.clone(),
+        rust_generics: Vec::new(),
+        cs_generics: Vec::new(),
+        class_getter: Some(class_getter),
+    };
+    let type_impl = TokenStream2::from(expand(&type_input, Semantics::Value));
+
+    TokenStream::from(quote! {
+        #(#attrs)*
+        #[repr(transparent)]
+        #vis struct #ident(pub #repr);
+
+        #[allow(non_upper_case_globals)]
+        impl #ident {
+            #(
+                #(#variant_attrs)*
+                pub const #variants: Self = Self(#values);
+            )*
+        }
+
+        #type_impl
+
+        impl ::std::convert::From<#repr> for #ident {
+            fn from(value: #repr) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::std::convert::From<#ident> for #repr {
+            fn from(value: #ident) -> Self {
+                value.0
+            }
+        }
+    })
+}
+
 impl Input {
     fn type_trait(&self) -> TokenStream2 {
         let path = &self.path;
@@ -162,6 +336,11 @@ impl Input {
         quote!(#path :: Il2CppClass)
     }
 
+    fn error_ty(&self) -> TokenStream2 {
+        let path = &self.path;
+        quote!(#path :: Il2CppError)
+    }
+
     fn type_ty(&self) -> TokenStream2 {
         let path = &self.path;
         quote!(#path :: Il2CppType)
@@ -183,34 +362,42 @@ impl Input {
         }
     }
 
-    fn class_getter(&self) -> Option<TokenStream2> {
+    fn class_getter(&self) -> TokenStream2 {
         let class_ty = self.class_ty();
-
-        if let Some(getter) = &self.class_getter {
-            return Some(quote! {
-                fn class() -> &'static #class_ty #getter
-            });
-        }
-
-        if self.cs_generics.is_empty() {
-            return None;
-        }
-
+        let error_ty = self.error_ty();
         let namespace = &self.namespace;
         let class = &self.class;
         let generics = &self.cs_generics;
 
-        Some(quote! {
-            fn class() -> &'static #class_ty {
-                static CLASS: ::std::sync::OnceLock<&'static #class_ty> = ::std::sync::OnceLock::new();
-                CLASS.get_or_init(|| {
-                    #class_ty::find(#namespace, #class).unwrap()
-                        .make_generic::<(#(#generics),*)>()
-                        .unwrap()
-                        .unwrap()
+        let try_class = if let Some(getter) = &self.class_getter {
+            quote!(::std::result::Result::Ok(#getter))
+        } else if generics.is_empty() {
+            quote! {
+                #class_ty::find(#namespace, #class).ok_or_else(|| #error_ty::ClassNotFound {
+                    namespace: #namespace.to_owned(),
+                    name: #class.to_owned(),
                 })
             }
-        })
+        } else {
+            quote! {
+                // Statics are shared between all instantiations of a generic
+                // function, so caching is left to `make_generic`
+                let not_found = || #error_ty::ClassNotFound {
+                    namespace: #namespace.to_owned(),
+                    name: #class.to_owned(),
+                };
+                #class_ty::find(#namespace, #class)
+                    .ok_or_else(not_found)?
+                    .make_generic::<(#(#generics),*)>()?
+                    .ok_or_else(not_found)
+            }
+        };
+
+        quote! {
+            fn try_class() -> ::std::result::Result<&'static #class_ty, #error_ty<'static>> {
+                #try_class
+            }
+        }
     }
 
     fn reference_match_fns(&self) -> TokenStream2 {
@@ -261,27 +448,47 @@ impl Input {
 
         let impl_ = quote!(impl<#(#generics: #type_trait),*>);
         let type_ = quote!(#ty<#(#generics),*>);
+        let nullable_extras = self.nullable_extras();
 
         quote! {
             unsafe #impl_ #path::Argument for #type_ {
                 type Type = Self;
 
+                fn resolve() -> ::std::result::Result<(), #path::Il2CppError<'static>> {
+                    <Self as #type_trait>::try_class().map(::std::mem::drop)
+                }
+
                 fn matches(ty: &#path::Il2CppType) -> bool {
                     <Self as #type_trait>::matches_value_argument(ty)
                 }
 
-                fn invokable(&mut self) -> *mut ::std::ffi::c_void {
-                    self as *mut Self as *mut ::std::ffi::c_void
+                fn type_name() -> ::std::string::String {
+                    <Self as #type_trait>::class().ty().to_string()
+                }
+
+                fn with_invokable<O>(
+                    &mut self,
+                    f: impl ::std::ops::FnOnce(*mut ::std::ffi::c_void) -> O,
+                ) -> O {
+                    f(self as *mut Self as *mut ::std::ffi::c_void)
                 }
             }
 
             unsafe #impl_ #path::Parameter for #type_ {
                 type Actual = Self;
 
+                fn resolve() -> ::std::result::Result<(), #path::Il2CppError<'static>> {
+                    <Self as #type_trait>::try_class().map(::std::mem::drop)
+                }
+
                 fn matches(ty: &#path::Il2CppType) -> bool {
                     <Self as #type_trait>::matches_value_parameter(ty)
                 }
 
+                fn type_name() -> ::std::string::String {
+                    <Self as #type_trait>::class().ty().to_string()
+                }
+
                 fn from_actual(actual: Self::Actual) -> Self {
                     actual
                 }
@@ -293,10 +500,18 @@ impl Input {
             unsafe #impl_ #path::Returned for #type_ {
                 type Type = Self;
 
+                fn resolve() -> ::std::result::Result<(), #path::Il2CppError<'static>> {
+                    <Self as #type_trait>::try_class().map(::std::mem::drop)
+                }
+
                 fn matches(ty: &#path::Il2CppType) -> bool {
                     <Self as #type_trait>::matches_returned(ty)
                 }
 
+                fn type_name() -> ::std::string::String {
+                    <Self as #type_trait>::class().ty().to_string()
+                }
+
                 fn from_object(object: Option<&mut #path::Il2CppObject>) -> Self {
                     unsafe { #path::raw::unbox(#path::WrapRaw::raw(object.unwrap())) }
                 }
@@ -305,10 +520,18 @@ impl Input {
             unsafe #impl_ #path::Return for #type_ {
                 type Actual = Self;
 
+                fn resolve() -> ::std::result::Result<(), #path::Il2CppError<'static>> {
+                    <Self as #type_trait>::try_class().map(::std::mem::drop)
+                }
+
                 fn matches(ty: &#path::Il2CppType) -> bool {
                     <Self as #type_trait>::matches_return(ty)
                 }
 
+                fn type_name() -> ::std::string::String {
+                    <Self as #type_trait>::class().ty().to_string()
+                }
+
                 fn into_actual(self) -> Self::Actual {
                     self
                 }
@@ -316,6 +539,150 @@ impl Input {
                     actual
                 }
             }
+
+            #nullable_extras
+        }
+    }
+
+    /// `Option<T>` maps to `System.Nullable<T>`, but the orphan rule only
+    /// allows implementing traits for it in libil2cpp itself, so other crates
+    /// have to use `Il2CppNullable<T>` directly
+    ///
+    /// `Option<T>` doesn't have the same layout as `Il2CppNullable<T>`, so
+    /// values held in fields and arrays are `Il2CppNullable<T>`, and arguments
+    /// are converted to it for the duration of the call
+    fn nullable_extras(&self) -> TokenStream2 {
+        let ty = &self.ty;
+        let type_trait = self.type_trait();
+        let generics = &self.rust_generics;
+        let path = &self.path;
+
+        if !path.is_ident("crate") {
+            return TokenStream2::new();
+        }
+
+        let impl_ = quote!(impl<#(#generics: #type_trait),*>);
+        let type_ = quote!(#ty<#(#generics),*>);
+        let nullable = quote!(#path::Il2CppNullable<#type_>);
+
+        quote! {
+            unsafe #impl_ #type_trait for ::std::option::Option<#type_> {
+                type Held<'a> = #nullable;
+                type HeldRaw = #nullable;
+
+                const NAMESPACE: &'static str = <#nullable as #type_trait>::NAMESPACE;
+                const CLASS_NAME: &'static str = <#nullable as #type_trait>::CLASS_NAME;
+
+                fn try_class() -> ::std::result::Result<&'static #path::Il2CppClass, #path::Il2CppError<'static>> {
+                    <#nullable as #type_trait>::try_class()
+                }
+
+                fn matches_reference_argument(ty: &#path::Il2CppType) -> bool {
+                    <#nullable as #type_trait>::matches_reference_argument(ty)
+                }
+
+                fn matches_value_argument(ty: &#path::Il2CppType) -> bool {
+                    <#nullable as #type_trait>::matches_value_argument(ty)
+                }
+
+                fn matches_reference_parameter(ty: &#path::Il2CppType) -> bool {
+                    <#nullable as #type_trait>::matches_reference_parameter(ty)
+                }
+
+                fn matches_value_parameter(ty: &#path::Il2CppType) -> bool {
+                    <#nullable as #type_trait>::matches_value_parameter(ty)
+                }
+            }
+
+            unsafe #impl_ #path::Argument for ::std::option::Option<#type_> {
+                type Type = #nullable;
+
+                fn resolve() -> ::std::result::Result<(), #path::Il2CppError<'static>> {
+                    <#nullable as #path::Argument>::resolve()
+                }
+
+                fn matches(ty: &#path::Il2CppType) -> bool {
+                    <#nullable as #path::Argument>::matches(ty)
+                }
+
+                fn type_name() -> ::std::string::String {
+                    <#nullable as #path::Argument>::type_name()
+                }
+
+                fn with_invokable<O>(
+                    &mut self,
+                    f: impl ::std::ops::FnOnce(*mut ::std::ffi::c_void) -> O,
+                ) -> O {
+                    let mut nullable: #nullable = (*self).into();
+                    #path::Argument::with_invokable(&mut nullable, f)
+                }
+            }
+
+            unsafe #impl_ #path::Parameter for ::std::option::Option<#type_> {
+                type Actual = #nullable;
+
+                fn resolve() -> ::std::result::Result<(), #path::Il2CppError<'static>> {
+                    <#nullable as #path::Parameter>::resolve()
+                }
+
+                fn matches(ty: &#path::Il2CppType) -> bool {
+                    <#nullable as #path::Parameter>::matches(ty)
+                }
+
+                fn type_name() -> ::std::string::String {
+                    <#nullable as #path::Parameter>::type_name()
+                }
+
+                fn from_actual(actual: Self::Actual) -> Self {
+                    actual.into()
+                }
+                fn into_actual(self) -> Self::Actual {
+                    self.into()
+                }
+            }
+
+            unsafe #impl_ #path::Returned for ::std::option::Option<#type_> {
+                type Type = #nullable;
+
+                fn resolve() -> ::std::result::Result<(), #path::Il2CppError<'static>> {
+                    <#nullable as #path::Returned>::resolve()
+                }
+
+                fn matches(ty: &#path::Il2CppType) -> bool {
+                    <#nullable as #path::Returned>::matches(ty)
+                }
+
+                fn type_name() -> ::std::string::String {
+                    <#nullable as #path::Returned>::type_name()
+                }
+
+                fn from_object(object: Option<&mut #path::Il2CppObject>) -> Self {
+                    <#nullable as #path::Returned>::from_object(object).into()
+                }
+            }
+
+            unsafe #impl_ #path::Return for ::std::option::Option<#type_> {
+                type Actual = #nullable;
+
+                fn resolve() -> ::std::result::Result<(), #path::Il2CppError<'static>> {
+                    <#nullable as #path::Return>::resolve()
+                }
+
+                fn matches(ty: &#path::Il2CppType) -> bool {
+                    <#nullable as #path::Return>::matches(ty)
+                }
+
+                fn type_name() -> ::std::string::String {
+                    <#nullable as #path::Return>::type_name()
+                }
+
+                fn into_actual(self) -> Self::Actual {
+                    self.into()
+                }
+                fn from_actual(actual: Self::Actual) -> Self {
+                    actual.into()
+                }
+            }
         }
     }
 }
@@ -75,6 +75,7 @@
 mod hook;
 mod il2cpp_functions;
 mod impl_arguments_parameters;
+mod impl_callbacks;
 mod impl_generics;
 mod impl_type;
 
@@ -86,19 +87,58 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
-    parse_macro_input, Error, Expr, ExprLit, ExprRange, ItemFn, Lit, LitStr, RangeLimits, Result,
-    Token,
+    parse_macro_input, Error, Expr, ExprLit, ExprRange, ItemFn, Lit, RangeLimits, Result, Token,
 };
 
 /// Creates an inline hook at a C# method.
 ///
+/// The method is specified by its namespace, class name and name, optionally
+/// followed by named arguments.
+///
+/// * `priority = <i32>` sets the position of the hook in the chain of hooks
+///   targeting the same method, hooks with higher priorities being called
+///   first. Defaults to `0`.
+/// * `signature = "<return type> (<parameter types>)"` selects the overload
+///   with exactly these C# types, for when the Rust types alone match several
+///   overloads. The return type can be omitted.
+/// * `params = ["<parameter type>", ...]` does the same without specifying a
+///   return type.
+///
+/// Builtin types can be written using either their short name (`int`) or
+/// their fully qualified name (`System.Int32`), and ref types end with `&`.
+///
+/// Instantiations of generic classes and methods are hooked by writing their
+/// generic arguments as fully qualified names, as in
+/// ``List`1<UnityEngine.GameObject>`` or `GetComponent<UnityEngine.Transform>`.
+/// Several instantiations of a class can be hooked at once by giving a list of
+/// them instead of a single class name. Instantiations using reference types
+/// share the same code, in which case the hook is only called for the ones it
+/// targets.
+///
+/// ```ignore
+/// #[hook("UnityEngine.SceneManagement", "SceneManager", "SetActiveScene", priority = 10)]
+/// fn set_active_scene(scene: &mut Il2CppObject) -> bool {
+///     set_active_scene.original(scene)
+/// }
+///
+/// #[hook("UnityEngine", "Debug", "Log", signature = "void (System.Object)")]
+/// fn log(message: &mut Il2CppObject) {
+///     log.original(message)
+/// }
+///
+/// #[hook("System.Collections.Generic", ["List`1<UnityEngine.GameObject>", "List`1<UnityEngine.Transform>"], "Add")]
+/// fn add(this: &mut Il2CppObject, item: Option<&mut Il2CppObject>) {
+///     add.original(this, item)
+/// }
+/// ```
+///
 /// # Panics
 ///
 /// * `original` will panic if the hook has not yet been installed.
 #[proc_macro_attribute]
 pub fn hook(attr: TokenStream, item: TokenStream) -> TokenStream {
     let args =
-        parse_macro_input!(attr with Punctuated<LitStr, Token![,]>::parse_separated_nonempty);
+        parse_macro_input!(attr with Punctuated<hook::Arg, Token![,]>::parse_separated_nonempty);
     let input = parse_macro_input!(item as ItemFn);
 
     match hook::expand(&args, input) {
@@ -233,6 +273,61 @@ pub fn unsafe_impl_value_type(input: TokenStream) -> TokenStream {
     impl_type::expand(&input, impl_type::Semantics::Value)
 }
 
+/// Implements the `Type` trait for a Rust enum that is equivalent to a C# enum
+///
+/// Note that in order to use this macros, the `generic_associated_types` and
+/// `once_cell` features must be enabled.
+///
+/// The enum is written inside the macro invocation and must have an integer
+/// representation matching the underlying type of the C# enum, and no
+/// variants with fields. The first time the class of the type is requested, it
+/// is checked to be an enum with this underlying type and to have a value for
+/// every variant of the Rust enum, and an error is returned otherwise.
+///
+/// Since C# enums can hold any value of their underlying type, the enum is
+/// turned into a `#[repr(transparent)]` newtype around it, with an associated
+/// constant for each variant. Deriving `PartialEq` and `Eq` allows matching on
+/// these constants, with a wildcard arm for the values the Rust enum doesn't
+/// cover. `From` conversions between the type and its underlying type are
+/// also implemented.
+///
+/// # Safety
+///
+/// The C# type must be the enum the Rust enum describes.
+///
+/// # Examples
+///
+/// The basic syntax follows the pattern `in <libil2cpp path> => <C# type>
+/// <Rust enum>`.
+///
+/// ```ignore
+/// #![feature(generic_associated_types, once_cell)]
+///
+/// unsafe_impl_enum_type! {
+///     in libil2cpp => UnityEngine.FilterMode
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     #[repr(i32)]
+///     enum FilterMode {
+///         Point = 0,
+///         Bilinear = 1,
+///         Trilinear = 2,
+///     }
+/// }
+///
+/// fn is_smooth(mode: FilterMode) -> bool {
+///     match mode {
+///         FilterMode::Point => false,
+///         FilterMode::Bilinear | FilterMode::Trilinear => true,
+///         _ => false,
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn unsafe_impl_enum_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as impl_type::EnumInput);
+    impl_type::expand_enum(&input)
+}
+
 #[proc_macro]
 #[doc(hidden)]
 pub fn il2cpp_functions(input: TokenStream) -> TokenStream {
@@ -253,6 +348,16 @@ pub fn impl_arguments_parameters(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro]
+#[doc(hidden)]
+pub fn impl_callbacks(input: TokenStream) -> TokenStream {
+    let range = parse_macro_input!(input as RangeInput);
+    match impl_callbacks::expand(range.0) {
+        Ok(ts) => ts,
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 #[proc_macro]
 #[doc(hidden)]
 pub fn impl_generics(input: TokenStream) -> TokenStream {
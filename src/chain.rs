@@ -0,0 +1,387 @@
+//! Registry allowing several hooks to target the same method
+//!
+//! Every hooked method has a single inline hook redirecting it to the first
+//! hook of its chain. Each hook in the chain then calls the next one as its
+//! original, and the last one calls the actual hooked method. Hooks with higher
+//! priorities are called first, and hooks with the same priority are called in
+//! installation order.
+//!
+//! The function each hook calls as its original is stored in a static of the
+//! hook itself, which is updated whenever the chain changes, so calling the
+//! original doesn't go through the registry.
+//!
+//! The registry lives inside this crate, so only hooks installed through the
+//! same copy of `quest_hook` share a chain. Hooks installed through other
+//! copies, such as the ones of other mods, patch the method again on top of
+//! the existing patch, so the chain patched last calls the other one as its
+//! original. Since unpatching the method would also remove the patches applied
+//! on top of it, a chain can only be uninstalled or get a new first hook while
+//! its patch is the last one applied. Otherwise an error is returned and the
+//! chain is left as it was.
+
+use std::collections::BTreeMap;
+use std::ptr::null_mut;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use inline_hook::Hook;
+
+use crate::{HookInstallError, HookUninstallError};
+
+static CHAINS: Mutex<BTreeMap<usize, Chain>> = Mutex::new(BTreeMap::new());
+
+/// Hooks targeting a single method, sorted by call order
+#[derive(Debug)]
+struct Chain {
+    inline: Hook,
+    links: Vec<Link>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Link {
+    hook: usize,
+    priority: i32,
+    next: &'static AtomicPtr<()>,
+}
+
+impl Chain {
+    fn head(&self) -> Option<usize> {
+        self.links.first().map(|l| l.hook)
+    }
+
+    /// Points the inline hook at the head of the chain if it changed from
+    /// `previous_head`, pointing it back at `previous_head` on failure
+    unsafe fn redirect(&self, target: usize, previous_head: Option<usize>) -> bool {
+        let head = match self.head() {
+            Some(head) if Some(head) != previous_head || !self.inline.is_installed() => head,
+            _ => return true,
+        };
+        if self.inline.is_installed() && !self.inline.uninstall() {
+            return false;
+        }
+        if self.inline.install(target as *const (), head as *const ()) {
+            return true;
+        }
+
+        // The chain is left without an inline hook if this fails as well, and
+        // the next change to the chain tries to install it again
+        if let Some(previous_head) = previous_head {
+            self.inline
+                .install(target as *const (), previous_head as *const ());
+        }
+        false
+    }
+
+    /// Updates the function each hook calls as its original
+    fn relink(&self) {
+        let original = self.inline.original().map_or(null_mut(), |o| o as *mut ());
+        for (i, link) in self.links.iter().enumerate() {
+            let next = self
+                .links
+                .get(i + 1)
+                .map_or(original, |next| next.hook as *mut ());
+            link.next.store(next, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Adds `hook` to the chain of hooks targeting `target`, storing the function
+/// it should call as its original in `next` until it is uninstalled
+///
+/// # Safety
+/// `target` and `hook` must have the same signature and calling convention.
+///
+/// When `hook` becomes the first hook of the chain, `target` is unpatched and
+/// patched again, so no thread may be calling `target` or the original of any
+/// hook of the chain at that point.
+pub unsafe fn install(
+    target: *const (),
+    hook: *const (),
+    next: &'static AtomicPtr<()>,
+    priority: i32,
+) -> Result<(), HookInstallError> {
+    let (target, hook) = (target as usize, hook as usize);
+    let mut chains = CHAINS.lock().unwrap_or_else(PoisonError::into_inner);
+    let chain = chains.entry(target).or_insert_with(|| Chain {
+        inline: Hook::new(),
+        links: Vec::new(),
+    });
+
+    if chain.links.iter().any(|l| l.hook == hook) {
+        return Err(HookInstallError::AlreadyInstalled);
+    }
+
+    let previous_head = chain.head();
+    let index = chain.links.partition_point(|l| l.priority >= priority);
+    chain.links.insert(
+        index,
+        Link {
+            hook,
+            priority,
+            next,
+        },
+    );
+
+    if chain.redirect(target, previous_head) {
+        chain.relink();
+        return Ok(());
+    }
+
+    chain.links.remove(index);
+    chain.relink();
+    if chain.links.is_empty() {
+        chains.remove(&target);
+    }
+    Err(HookInstallError::InstallError)
+}
+
+/// Removes `hook` from the chain of hooks targeting `target`, restoring the
+/// method once the chain is empty
+///
+/// # Safety
+/// No thread may be executing `hook` or calling its original.
+///
+/// When `hook` is the first hook of the chain, `target` is unpatched and
+/// patched again, so no thread may be calling `target` or the original of any
+/// hook of the chain at that point.
+pub unsafe fn uninstall(target: *const (), hook: *const ()) -> Result<(), HookUninstallError> {
+    let (target, hook) = (target as usize, hook as usize);
+    let mut chains = CHAINS.lock().unwrap_or_else(PoisonError::into_inner);
+    let chain = chains
+        .get_mut(&target)
+        .ok_or(HookUninstallError::NotInstalled)?;
+    let index = chain
+        .links
+        .iter()
+        .position(|l| l.hook == hook)
+        .ok_or(HookUninstallError::NotInstalled)?;
+
+    let previous_head = chain.head();
+    let link = chain.links.remove(index);
+
+    let removed = if chain.links.is_empty() {
+        chain.inline.uninstall()
+    } else {
+        chain.redirect(target, previous_head)
+    };
+    if !removed {
+        chain.links.insert(index, link);
+        chain.relink();
+        return Err(HookUninstallError::UninstallError);
+    }
+
+    link.next.store(null_mut(), Ordering::SeqCst);
+    chain.relink();
+    if chain.links.is_empty() {
+        chains.remove(&target);
+    }
+    Ok(())
+}
+
+/// Whether `hook` is part of the chain of hooks targeting `target`
+pub fn is_installed(target: *const (), hook: *const ()) -> bool {
+    let (target, hook) = (target as usize, hook as usize);
+    let chains = CHAINS.lock().unwrap_or_else(PoisonError::into_inner);
+    chains
+        .get(&target)
+        .is_some_and(|c| c.links.iter().any(|l| l.hook == hook))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::transmute;
+    use std::sync::atomic::{AtomicPtr, Ordering};
+
+    use inline_hook::Hook;
+
+    use super::{install, is_installed, uninstall};
+    use crate::{HookInstallError, HookUninstallError};
+
+    type Fn = extern "C" fn(usize, usize) -> usize;
+
+    /// Defines a hook which appends `digit` to the result of its original
+    macro_rules! link {
+        ($name:ident, $digit:literal) => {
+            mod $name {
+                use super::*;
+
+                pub static NEXT: AtomicPtr<()> = AtomicPtr::new(std::ptr::null_mut());
+
+                pub extern "C" fn hook(n1: usize, n2: usize) -> usize {
+                    let next = unsafe { transmute::<*mut (), Fn>(NEXT.load(Ordering::SeqCst)) };
+                    next(n1, n2) * 10 + $digit
+                }
+            }
+        };
+    }
+
+    unsafe fn add_link(target: Fn, hook: Fn, next: &'static AtomicPtr<()>, priority: i32) {
+        install(target as _, hook as _, next, priority).unwrap();
+    }
+
+    unsafe fn remove_link(target: Fn, hook: Fn) {
+        uninstall(target as _, hook as _).unwrap();
+    }
+
+    #[test]
+    fn priority_order() {
+        #[inline(never)]
+        extern "C" fn add(n1: usize, n2: usize) -> usize {
+            n1 + n2
+        }
+
+        link!(a, 1);
+        link!(b, 2);
+        link!(c, 3);
+        link!(d, 4);
+
+        unsafe {
+            add_link(add, a::hook, &a::NEXT, 0);
+            assert_eq!(add(0, 0), 1);
+            add_link(add, c::hook, &c::NEXT, 2);
+            assert_eq!(add(0, 0), 13);
+            add_link(add, b::hook, &b::NEXT, 1);
+            assert_eq!(add(0, 0), 123);
+            add_link(add, d::hook, &d::NEXT, 1);
+            assert_eq!(add(0, 0), 1423);
+        }
+
+        assert_eq!(
+            unsafe { install(add as _, b::hook as _, &b::NEXT, 5) },
+            Err(HookInstallError::AlreadyInstalled)
+        );
+
+        unsafe {
+            remove_link(add, c::hook);
+            remove_link(add, a::hook);
+            remove_link(add, d::hook);
+            remove_link(add, b::hook);
+        }
+        assert_eq!(add(0, 0), 0);
+        assert!(b::NEXT.load(Ordering::SeqCst).is_null());
+    }
+
+    #[test]
+    fn remove_from_middle() {
+        #[inline(never)]
+        extern "C" fn or(n1: usize, n2: usize) -> usize {
+            n1 | n2
+        }
+
+        link!(a, 1);
+        link!(b, 2);
+        link!(c, 3);
+
+        unsafe {
+            add_link(or, a::hook, &a::NEXT, 1);
+            add_link(or, b::hook, &b::NEXT, 2);
+            add_link(or, c::hook, &c::NEXT, 3);
+        }
+        assert_eq!(or(0, 0), 123);
+
+        unsafe { remove_link(or, b::hook) };
+        assert_eq!(or(0, 0), 13);
+        assert!(!is_installed(or as _, b::hook as _));
+        assert!(b::NEXT.load(Ordering::SeqCst).is_null());
+        assert_eq!(
+            unsafe { uninstall(or as _, b::hook as _) },
+            Err(HookUninstallError::NotInstalled)
+        );
+
+        unsafe {
+            remove_link(or, c::hook);
+            remove_link(or, a::hook);
+        }
+        assert_eq!(or(0, 0), 0);
+    }
+
+    #[test]
+    fn failed_install() {
+        #[inline(never)]
+        extern "C" fn xor(n1: usize, n2: usize) -> usize {
+            n1 ^ n2
+        }
+
+        link!(a, 1);
+        static NEXT: AtomicPtr<()> = AtomicPtr::new(std::ptr::null_mut());
+
+        unsafe { add_link(xor, a::hook, &a::NEXT, 0) };
+
+        // A method can't be redirected to itself, so this fails after the
+        // inline hook got removed to point it to the new head
+        assert_eq!(
+            unsafe { install(xor as _, xor as _, &NEXT, 1) },
+            Err(HookInstallError::InstallError)
+        );
+        assert!(!is_installed(xor as _, xor as _));
+        assert!(is_installed(xor as _, a::hook as _));
+        assert_eq!(xor(0, 0), 1);
+
+        unsafe { remove_link(xor, a::hook) };
+        assert_eq!(xor(0, 0), 0);
+    }
+
+    #[test]
+    fn failed_uninstall() {
+        #[inline(never)]
+        extern "C" fn sub(n1: usize, n2: usize) -> usize {
+            n1 - n2
+        }
+
+        link!(a, 1);
+        static NEXT: AtomicPtr<()> = AtomicPtr::new(std::ptr::null_mut());
+
+        unsafe {
+            add_link(sub, a::hook, &a::NEXT, 1);
+            add_link(sub, sub, &NEXT, 0);
+        }
+
+        // Removing the head fails when the method can't be redirected to the
+        // next hook, which is the method itself
+        assert_eq!(
+            unsafe { uninstall(sub as _, a::hook as _) },
+            Err(HookUninstallError::UninstallError)
+        );
+        assert!(is_installed(sub as _, a::hook as _));
+
+        unsafe { remove_link(sub, sub) };
+        assert_eq!(sub(0, 0), 1);
+
+        unsafe { remove_link(sub, a::hook) };
+        assert_eq!(sub(0, 0), 0);
+    }
+
+    #[test]
+    fn patched_over() {
+        #[inline(never)]
+        extern "C" fn and(n1: usize, n2: usize) -> usize {
+            n1 & n2
+        }
+
+        static OTHER: Hook = Hook::new();
+        extern "C" fn other(n1: usize, n2: usize) -> usize {
+            let original = unsafe { transmute::<*const (), Fn>(OTHER.original().unwrap()) };
+            original(n1, n2) * 10 + 9
+        }
+
+        link!(a, 1);
+
+        unsafe {
+            add_link(and, a::hook, &a::NEXT, 0);
+            assert!(OTHER.install(and as _, other as _));
+        }
+        assert_eq!(and(0, 0), 19);
+
+        // Unpatching the method would remove the other hook
+        assert_eq!(
+            unsafe { uninstall(and as _, a::hook as _) },
+            Err(HookUninstallError::UninstallError)
+        );
+        assert_eq!(and(0, 0), 19);
+
+        assert!(unsafe { OTHER.uninstall() });
+        assert_eq!(and(0, 0), 1);
+        unsafe { remove_link(and, a::hook) };
+        assert_eq!(and(0, 0), 0);
+    }
+}
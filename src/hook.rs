@@ -11,17 +11,27 @@ pub trait Hook {
 
     /// Namespace of the hooked method's class
     const NAMESPACE: &'static str;
-    /// Name of the hooked method's class
+    /// Name of the hooked method's class, or of the first hooked instantiation
+    /// of a generic class
     const CLASS_NAME: &'static str;
+    /// Names of all the hooked instantiations of a generic class, or only
+    /// [`CLASS_NAME`](Self::CLASS_NAME) for other classes
+    const CLASS_NAMES: &'static [&'static str];
     /// Name of the hooked method
     const METHOD_NAME: &'static str;
+    /// Priority of the hook in the [`chain`](crate::chain) of hooks targeting
+    /// the same method, hooks with higher priorities being called first
+    const PRIORITY: i32;
 
     /// Installs the hook
     fn install(&self) -> Result<(), HookInstallError>;
+    /// Uninstalls the hook, restoring the hooked method
+    fn uninstall(&self) -> Result<(), HookUninstallError>;
 
     /// Pointer to the hook function
     fn hook(&self) -> *const ();
-    /// Pointer to the hooked method, if installed
+    /// Pointer to the function called as the original, either the next hook in
+    /// the chain or the hooked method, if installed
     fn original(&self) -> Option<*const ()>;
 }
 
@@ -40,7 +50,28 @@ pub enum HookInstallError {
     #[error("method not found")]
     MethodNotFound,
 
+    /// The hooked instantiations of a generic method don't share the same
+    /// code, so they can't be hooked together
+    #[error("hooked instantiations don't share the same code")]
+    UnsharedInstantiations,
+
     /// Error installing hook
     #[error("error installing hook")]
     InstallError,
 }
+
+/// Possible errors when uninstalling a hook
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HookUninstallError {
+    /// Hook not installed
+    #[error("hook not installed")]
+    NotInstalled,
+
+    /// Error uninstalling hook
+    ///
+    /// This is also returned when the hooked method was patched again by a
+    /// hook installed through another copy of this crate, since unpatching it
+    /// would remove the other hook.
+    #[error("error uninstalling hook")]
+    UninstallError,
+}
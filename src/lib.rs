@@ -75,6 +75,8 @@
 #[macro_use]
 mod cfg;
 
+pub mod chain;
+
 mod hook;
 pub use hook::*;
 
@@ -0,0 +1,316 @@
+//! Generation of the raw il2cpp types from libil2cpp headers
+//!
+//! The bindings are generated with the `bindgen` command line tool, which can
+//! be overridden with the `BINDGEN` environment variable, and `libclang`.
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{env, fs, io};
+
+use crate::{layout, workspace_root, Result};
+
+/// Version of the `bindgen` command line tool the bindings are generated with,
+/// since the output and the accepted flags change between versions
+const BINDGEN_VERSION: &str = "0.70.1";
+
+/// Headers defining the il2cpp types, relative to the libil2cpp directory
+const HEADERS: &[&str] = &[
+    "il2cpp-config.h",
+    "il2cpp-api-types.h",
+    "il2cpp-class-internals.h",
+    "il2cpp-object-internals.h",
+    "il2cpp-tabledefs.h",
+];
+
+/// Types to generate bindings for, along with the types they depend on
+const ALLOWLIST_TYPES: &[&str] = &[
+    "Il2Cpp.*",
+    "MethodInfo",
+    "FieldInfo",
+    "ParameterInfo",
+    "PropertyInfo",
+    "EventInfo",
+    "VirtualInvokeData",
+];
+
+/// Constants and macros to generate bindings for
+const ALLOWLIST_VARS: &[&str] = &[
+    "Il2Cpp.*",
+    "kIl2Cpp.*",
+    "kInvalidIl2Cpp.*",
+    "[A-Z_]+_ATTRIBUTE_.*",
+    "THREAD_STATIC_FIELD_OFFSET",
+];
+
+struct Options {
+    version: String,
+    headers: PathBuf,
+    target: Option<String>,
+    check: bool,
+    clang_args: Vec<String>,
+}
+
+pub fn run(args: &[String]) -> Result<()> {
+    let options = Options::parse(args)?;
+    let path = workspace_root()
+        .join("libil2cpp/src/raw")
+        .join(format!("types_{}.rs", options.version));
+
+    let bindings = generate(&options)?;
+
+    let differences = match fs::read_to_string(&path) {
+        Ok(existing) => layout::compare(&layout::parse(&existing), &layout::parse(&bindings)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound && !options.check => Vec::new(),
+        Err(error) => return Err(format!("failed to read {}: {}", path.display(), error).into()),
+    };
+    for difference in &differences {
+        eprintln!("{}", difference);
+    }
+
+    if options.check {
+        if !differences.is_empty() {
+            return Err(format!(
+                "{} layout differences with {}",
+                differences.len(),
+                path.display()
+            )
+            .into());
+        }
+    } else {
+        fs::write(&path, bindings)?;
+        eprintln!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Self> {
+        let mut positional = Vec::new();
+        let mut target = None;
+        let mut check = false;
+        let mut clang_args = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--target" => match args.next() {
+                    Some(triple) => target = Some(triple.clone()),
+                    None => return Err("missing value for --target".into()),
+                },
+                "--check" => check = true,
+                "--" => {
+                    clang_args.extend(args.by_ref().cloned());
+                }
+                _ => positional.push(arg.clone()),
+            }
+        }
+
+        let (version, headers) = match <[String; 2]>::try_from(positional) {
+            Ok([version, headers]) => (version, PathBuf::from(headers)),
+            Err(_) => return Err("expected a Unity version and a headers directory".into()),
+        };
+        if version.len() != 4 || !version.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!(
+                "invalid Unity version {}, expected a year such as 2019",
+                version
+            )
+            .into());
+        }
+        if !headers.join(HEADERS[0]).is_file() {
+            return Err(
+                format!("{} is not a libil2cpp headers directory", headers.display()).into(),
+            );
+        }
+
+        Ok(Self {
+            version,
+            headers,
+            target,
+            check,
+            clang_args,
+        })
+    }
+}
+
+/// Runs bindgen on the headers, returning the generated bindings
+fn generate(options: &Options) -> Result<String> {
+    let wrapper = env::temp_dir().join(format!("il2cpp-{}.hpp", options.version));
+    let includes = HEADERS
+        .iter()
+        .map(|header| format!("#include \"{}\"\n", header))
+        .collect::<String>();
+    fs::write(&wrapper, includes)?;
+
+    let bindgen = env::var_os("BINDGEN").unwrap_or_else(|| "bindgen".into());
+    check_version(&bindgen)?;
+    let mut command = Command::new(&bindgen);
+    command.arg(&wrapper).arg("--ignore-functions");
+    for ty in ALLOWLIST_TYPES {
+        command.args(["--allowlist-type", ty]);
+    }
+    for var in ALLOWLIST_VARS {
+        command.args(["--allowlist-var", var]);
+    }
+    command.args(["--", "-x", "c++", "-std=c++11"]);
+    command.arg(include_arg(&options.headers));
+    if let Some(target) = &options.target {
+        command.arg(format!("--target={}", target));
+    }
+    command.args(&options.clang_args);
+
+    let output = command
+        .output()
+        .map_err(|error| format!("failed to run {}: {}", bindgen.to_string_lossy(), error))?;
+    fs::remove_file(&wrapper)?;
+    if !output.status.success() {
+        return Err(format!(
+            "bindgen failed with {}:\n{}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+
+    let bindings = String::from_utf8(output.stdout)?;
+    Ok(format!("#![allow(warnings)]\n{}", bindings))
+}
+
+/// Checks that the `bindgen` command line tool is the pinned version
+fn check_version(bindgen: &OsStr) -> Result<()> {
+    let output = Command::new(bindgen)
+        .arg("--version")
+        .output()
+        .map_err(|error| format!("failed to run {}: {}", bindgen.to_string_lossy(), error))?;
+    // The version is printed as `bindgen <version>`
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let version = stdout.split_whitespace().nth(1).unwrap_or_default();
+    if version != BINDGEN_VERSION {
+        return Err(format!(
+            "expected bindgen {0} but found {1}, install it with `cargo install bindgen-cli --version {0}`",
+            BINDGEN_VERSION,
+            stdout.trim()
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn include_arg(headers: &Path) -> OsString {
+    let mut arg = OsString::from("-I");
+    arg.push(headers);
+    arg
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::ALLOWLIST_VARS;
+    use crate::workspace_root;
+
+    /// Matches the subset of regular expressions used in the allowlists,
+    /// which is literals, `.` and `[A-Z_]` followed by an optional `*` or `+`
+    fn matches(pattern: &[u8], name: &[u8]) -> bool {
+        let Some((&first, rest)) = pattern.split_first() else {
+            return name.is_empty();
+        };
+        let (class, rest): (&dyn Fn(u8) -> bool, _) = match first {
+            b'.' => (&|_| true, rest),
+            b'[' => {
+                let end = rest.iter().position(|&c| c == b']').unwrap();
+                let set = &rest[..end];
+                (
+                    &move |c: u8| {
+                        set.windows(3)
+                            .any(|w| w[1] == b'-' && (w[0]..=w[2]).contains(&c))
+                            || set.contains(&c)
+                    },
+                    &rest[end + 1..],
+                )
+            }
+            c => (&move |n| n == c, rest),
+        };
+        match rest.first() {
+            Some(b'*' | b'+') => {
+                let min = usize::from(rest[0] == b'+');
+                let count = name.iter().take_while(|&&c| class(c)).count();
+                (min..=count).rev().any(|n| matches(&rest[1..], &name[n..]))
+            }
+            _ => name.first().is_some_and(|&c| class(c)) && matches(rest, &name[1..]),
+        }
+    }
+
+    /// Names of the constants used through `raw::` in the files of a directory
+    fn used_constants(dir: &Path, constants: &mut Vec<String>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                used_constants(&path, constants);
+                continue;
+            }
+            // The generated bindings only refer to their own types
+            let name = path.file_name().unwrap().to_string_lossy();
+            if path.extension().is_none_or(|e| e != "rs") || name.starts_with("types_") {
+                continue;
+            }
+            let source = fs::read_to_string(&path).unwrap();
+            for (_, rest) in source
+                .match_indices("raw::")
+                .map(|(i, _)| source.split_at(i + 5))
+            {
+                let names: Vec<_> = match rest.strip_prefix('{') {
+                    Some(list) => list[..list.find('}').unwrap()].split(',').collect(),
+                    None => vec![rest],
+                };
+                for name in names {
+                    let name: String = name
+                        .trim()
+                        .chars()
+                        .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                        .collect();
+                    let is_constant = name.starts_with('k')
+                        && name[1..].starts_with(|c: char| c.is_ascii_uppercase())
+                        || name.len() > 1
+                            && name.chars().all(|c| c.is_ascii_uppercase() || c == '_');
+                    if is_constant && !constants.contains(&name) {
+                        constants.push(name);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn allowlist_patterns() {
+        let matches = |pattern: &str, name: &str| matches(pattern.as_bytes(), name.as_bytes());
+        assert!(matches("kIl2Cpp.*", "kIl2CppSizeOfArray"));
+        assert!(matches("[A-Z_]+_ATTRIBUTE_.*", "METHOD_ATTRIBUTE_STATIC"));
+        assert!(!matches("[A-Z_]+_ATTRIBUTE_.*", "_ATTRIBUTE_STATIC"));
+        assert!(!matches("Il2Cpp.*", "kIl2CppSizeOfArray"));
+    }
+
+    #[test]
+    fn allowlist_covers_used_constants() {
+        let root = workspace_root();
+        let mut constants = Vec::new();
+        used_constants(&root.join("libil2cpp/src"), &mut constants);
+        used_constants(&root.join("src"), &mut constants);
+        assert!(constants.iter().any(|c| c == "kInvalidIl2CppMethodSlot"));
+
+        let missing: Vec<_> = constants
+            .iter()
+            .filter(|c| {
+                !ALLOWLIST_VARS
+                    .iter()
+                    .any(|p| matches(p.as_bytes(), c.as_bytes()))
+            })
+            .collect();
+        assert!(
+            missing.is_empty(),
+            "constants missing from the allowlist: {:?}",
+            missing
+        );
+    }
+}
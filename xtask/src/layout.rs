@@ -0,0 +1,258 @@
+//! Layout assertions from the tests bindgen generates alongside bindings
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Asserted layout facts of each type, such as its size or the offset of one
+/// of its fields, mapped to their value
+pub type Layouts = BTreeMap<(String, Fact), u64>;
+
+/// Layout fact about a type
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Fact {
+    Size,
+    Alignment,
+    Offset(String),
+}
+
+/// Difference in the layout of a type between two sets of bindings
+#[derive(Debug)]
+pub enum Difference {
+    Changed {
+        ty: String,
+        fact: Fact,
+        old: u64,
+        new: u64,
+    },
+    Removed {
+        ty: String,
+        fact: Fact,
+    },
+}
+
+/// Extracts the layout assertions from bindings
+///
+/// Both the `assert_eq!` based tests emitted by older bindgen versions and the
+/// constant assertions emitted by newer ones are supported.
+pub fn parse(bindings: &str) -> Layouts {
+    let mut layouts = Layouts::new();
+
+    // assert_eq!(<actual>, <expected>usize, concat!(<message>));
+    for assertion in bindings.split("assert_eq!(").skip(1) {
+        if let [_, expected, message, ..] = split_args(assertion).as_slice() {
+            if let (Some(value), Some(key)) = (parse_usize(expected), parse_concat(message)) {
+                layouts.insert(key, value);
+            }
+        }
+    }
+
+    // ["<message>"][<actual> - <expected>usize];
+    for line in bindings.lines() {
+        let assertion = line
+            .trim()
+            .strip_prefix("[\"")
+            .and_then(|rest| rest.split_once("\"]["))
+            .and_then(|(message, rest)| Some((message, rest.strip_suffix("];")?)));
+        if let Some((message, expr)) = assertion {
+            let value = expr
+                .rsplit_once(" - ")
+                .and_then(|(_, expected)| parse_usize(expected));
+            if let (Some(value), Some(key)) = (value, parse_message(message)) {
+                layouts.insert(key, value);
+            }
+        }
+    }
+
+    layouts
+}
+
+/// Compares the layouts of two sets of bindings
+///
+/// Facts which are only asserted by the old bindings are reported as removed
+/// if the new bindings still assert something about their type, so that types
+/// which are no longer generated at all are not reported.
+pub fn compare(old: &Layouts, new: &Layouts) -> Vec<Difference> {
+    let mut differences = Vec::new();
+    for ((ty, fact), &old_value) in old {
+        match new.get(&(ty.clone(), fact.clone())) {
+            Some(&new_value) => {
+                if new_value != old_value {
+                    differences.push(Difference::Changed {
+                        ty: ty.clone(),
+                        fact: fact.clone(),
+                        old: old_value,
+                        new: new_value,
+                    });
+                }
+            }
+            None => {
+                if new.keys().any(|(new_ty, _)| new_ty == ty) {
+                    differences.push(Difference::Removed {
+                        ty: ty.clone(),
+                        fact: fact.clone(),
+                    });
+                }
+            }
+        }
+    }
+    differences
+}
+
+/// Splits the arguments of a macro invocation, stopping at its closing
+/// delimiter
+fn split_args(s: &str) -> Vec<&str> {
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    let mut in_string = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            _ if in_string => {}
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' if depth == 0 => {
+                args.push(s[start..i].trim());
+                break;
+            }
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    args
+}
+
+fn parse_usize(s: &str) -> Option<u64> {
+    s.trim().strip_suffix("usize")?.parse().ok()
+}
+
+/// Parses a `concat!` of string literals and `stringify!`ed identifiers
+fn parse_concat(s: &str) -> Option<(String, Fact)> {
+    let inner = s.strip_prefix("concat!(")?;
+    let message = split_args(inner)
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            part.strip_prefix("stringify!(")
+                .and_then(|part| part.strip_suffix(')'))
+                .or_else(|| part.strip_prefix('"')?.strip_suffix('"'))
+        })
+        .collect::<Option<String>>()?;
+    parse_message(&message)
+}
+
+/// Parses messages such as `Size of: FieldInfo` or `Offset of field:
+/// FieldInfo::name`
+fn parse_message(message: &str) -> Option<(String, Fact)> {
+    if let Some(field) = message.strip_prefix("Offset of field: ") {
+        let (ty, field) = field.split_once("::")?;
+        Some((ty.to_owned(), Fact::Offset(field.to_owned())))
+    } else if let Some(ty) = message.strip_prefix("Alignment of ") {
+        Some((ty.to_owned(), Fact::Alignment))
+    } else {
+        let ty = message.strip_prefix("Size of")?;
+        let ty = ty.strip_prefix(':').unwrap_or(ty);
+        Some((ty.trim().to_owned(), Fact::Size))
+    }
+}
+
+impl fmt::Display for Fact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Size => f.write_str("size"),
+            Self::Alignment => f.write_str("alignment"),
+            Self::Offset(field) => write!(f, "offset of {}", field),
+        }
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Changed { ty, fact, old, new } => {
+                write!(f, "{}: {} changed from {} to {}", ty, fact, old, new)
+            }
+            Self::Removed { ty, fact } => write!(f, "{}: {} is no longer asserted", ty, fact),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, parse, split_args, Difference, Fact, Layouts};
+
+    const OLD: &str = include_str!("../testdata/bindings_0_57.rs");
+    const NEW: &str = include_str!("../testdata/bindings_0_70.rs");
+
+    fn fact(layouts: &Layouts, ty: &str, fact: Fact) -> Option<u64> {
+        layouts.get(&(ty.to_owned(), fact)).copied()
+    }
+
+    #[test]
+    fn args() {
+        assert_eq!(split_args("a, b) c"), ["a", "b"]);
+        assert_eq!(split_args("f(a, b), [c, d]);"), ["f(a, b)", "[c, d]"]);
+        assert_eq!(split_args("\"(,)\", a)"), ["\"(,)\"", "a"]);
+        assert_eq!(split_args("a"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn parse_assert_eq() {
+        let layouts = parse(OLD);
+        assert_eq!(layouts.len(), 15);
+        assert_eq!(fact(&layouts, "FieldInfo", Fact::Size), Some(32));
+        assert_eq!(fact(&layouts, "FieldInfo", Fact::Alignment), Some(8));
+        assert_eq!(
+            fact(&layouts, "FieldInfo", Fact::Offset("parent".into())),
+            Some(16)
+        );
+        assert_eq!(
+            fact(&layouts, "PropertyInfo", Fact::Offset("token".into())),
+            Some(36)
+        );
+    }
+
+    #[test]
+    fn parse_const() {
+        let layouts = parse(NEW);
+        assert_eq!(layouts.len(), 6);
+        assert_eq!(fact(&layouts, "FieldInfo", Fact::Size), Some(24));
+        assert_eq!(fact(&layouts, "FieldInfo", Fact::Alignment), Some(8));
+        assert_eq!(
+            fact(&layouts, "FieldInfo", Fact::Offset("token".into())),
+            Some(20)
+        );
+    }
+
+    #[test]
+    fn differences() {
+        let old = parse(OLD);
+        let new = parse(NEW);
+        assert!(compare(&old, &old).is_empty());
+
+        let differences = compare(&old, &new)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            differences,
+            [
+                "FieldInfo: size changed from 32 to 24",
+                "FieldInfo: offset of offset changed from 24 to 16",
+                "FieldInfo: offset of parent is no longer asserted",
+                "FieldInfo: offset of token changed from 28 to 20",
+            ]
+        );
+        assert!(matches!(
+            compare(&new, &old).as_slice(),
+            [
+                Difference::Changed { .. },
+                Difference::Changed { .. },
+                Difference::Changed { .. }
+            ]
+        ));
+    }
+}
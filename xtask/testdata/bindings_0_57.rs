@@ -0,0 +1,157 @@
+/* automatically generated by rust-bindgen 0.57.0 */
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FieldInfo {
+    pub name: *const ::std::os::raw::c_char,
+    pub type_: *const Il2CppType,
+    pub parent: *mut Il2CppClass,
+    pub offset: i32,
+    pub token: u32,
+}
+#[test]
+fn bindgen_test_layout_FieldInfo() {
+    assert_eq!(
+        ::std::mem::size_of::<FieldInfo>(),
+        32usize,
+        concat!("Size of: ", stringify!(FieldInfo))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<FieldInfo>(),
+        8usize,
+        concat!("Alignment of ", stringify!(FieldInfo))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<FieldInfo>())).name as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(FieldInfo),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<FieldInfo>())).type_ as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(FieldInfo),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<FieldInfo>())).parent as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(FieldInfo),
+            "::",
+            stringify!(parent)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<FieldInfo>())).offset as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(FieldInfo),
+            "::",
+            stringify!(offset)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<FieldInfo>())).token as *const _ as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(FieldInfo),
+            "::",
+            stringify!(token)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct PropertyInfo {
+    pub parent: *mut Il2CppClass,
+    pub name: *const ::std::os::raw::c_char,
+    pub get: *const MethodInfo,
+    pub set: *const MethodInfo,
+    pub attrs: u32,
+    pub token: u32,
+}
+#[test]
+fn bindgen_test_layout_PropertyInfo() {
+    assert_eq!(
+        ::std::mem::size_of::<PropertyInfo>(),
+        40usize,
+        concat!("Size of: ", stringify!(PropertyInfo))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<PropertyInfo>(),
+        8usize,
+        concat!("Alignment of ", stringify!(PropertyInfo))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<PropertyInfo>())).parent as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(PropertyInfo),
+            "::",
+            stringify!(parent)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<PropertyInfo>())).name as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(PropertyInfo),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<PropertyInfo>())).get as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(PropertyInfo),
+            "::",
+            stringify!(get)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<PropertyInfo>())).set as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(PropertyInfo),
+            "::",
+            stringify!(set)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<PropertyInfo>())).attrs as *const _ as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(PropertyInfo),
+            "::",
+            stringify!(attrs)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<PropertyInfo>())).token as *const _ as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(PropertyInfo),
+            "::",
+            stringify!(token)
+        )
+    );
+}
@@ -0,0 +1,19 @@
+/* automatically generated by rust-bindgen 0.70.1 */
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FieldInfo {
+    pub name: *const ::std::os::raw::c_char,
+    pub type_: *const Il2CppType,
+    pub offset: i32,
+    pub token: u32,
+}
+#[allow(clippy::unnecessary_operation, clippy::identity_op)]
+const _: () = {
+    ["Size of FieldInfo"][::std::mem::size_of::<FieldInfo>() - 24usize];
+    ["Alignment of FieldInfo"][::std::mem::align_of::<FieldInfo>() - 8usize];
+    ["Offset of field: FieldInfo::name"][::std::mem::offset_of!(FieldInfo, name) - 0usize];
+    ["Offset of field: FieldInfo::type_"][::std::mem::offset_of!(FieldInfo, type_) - 8usize];
+    ["Offset of field: FieldInfo::offset"][::std::mem::offset_of!(FieldInfo, offset) - 16usize];
+    ["Offset of field: FieldInfo::token"][::std::mem::offset_of!(FieldInfo, token) - 20usize];
+};